@@ -22,7 +22,7 @@ use super::input::{Input, InputT, Output};
 use crate::precompile::PrecompileOutput;
 use frame_support::log;
 use module_evm::{Context, ExitError, ExitSucceed, Precompile};
-use module_support::{AddressMapping as AddressMappingT, CurrencyIdMapping as CurrencyIdMappingT, DEXManager, SwapLimit};
+use module_support::{AddressMapping as AddressMappingT, CurrencyIdMapping as CurrencyIdMappingT, DEXManager, SwapLimit, SwapPath};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use primitives::{Balance, CurrencyId};
 use sp_runtime::RuntimeDebug;
@@ -124,6 +124,10 @@ where
 					path, supply_amount
 				);
 
+				let path = SwapPath::try_new(path).map_err(|e| {
+					let err_msg: &str = e.into();
+					ExitError::Other(err_msg.into())
+				})?;
 				let value = Dex::get_swap_amount(&path, SwapLimit::ExactSupply(supply_amount, Balance::MIN))
 					.map(|(_, target)| target)
 					.ok_or_else(|| ExitError::Other("Dex get_swap_target_amount failed".into()))?;
@@ -149,6 +153,10 @@ where
 					path, target_amount
 				);
 
+				let path = SwapPath::try_new(path).map_err(|e| {
+					let err_msg: &str = e.into();
+					ExitError::Other(err_msg.into())
+				})?;
 				let value = Dex::get_swap_amount(&path, SwapLimit::ExactTarget(Balance::MAX, target_amount))
 					.map(|(supply, _)| supply)
 					.ok_or_else(|| ExitError::Other("Dex get_swap_supply_amount failed".into()))?;
@@ -176,6 +184,10 @@ where
 					who, path, supply_amount, min_target_amount
 				);
 
+				let path = SwapPath::try_new(path).map_err(|e| {
+					let err_msg: &str = e.into();
+					ExitError::Other(err_msg.into())
+				})?;
 				let (_, value) =
 					Dex::swap_with_specific_path(&who, &path, SwapLimit::ExactSupply(supply_amount, min_target_amount)).map_err(|e| {
 						let err_msg: &str = e.into();
@@ -205,6 +217,10 @@ where
 					who, path, target_amount, max_supply_amount
 				);
 
+				let path = SwapPath::try_new(path).map_err(|e| {
+					let err_msg: &str = e.into();
+					ExitError::Other(err_msg.into())
+				})?;
 				let (value, _) =
 					Dex::swap_with_specific_path(&who, &path, SwapLimit::ExactTarget(max_supply_amount, target_amount)).map_err(|e| {
 						let err_msg: &str = e.into();
@@ -30,7 +30,7 @@ use frame_support::{
 };
 use frame_system::{EnsureRoot, EnsureSignedBy};
 use module_support::{
-	mocks::MockAddressMapping, AddressMapping as AddressMappingT, SerpTreasury,
+	mocks::MockAddressMapping, AddressMapping as AddressMappingT, SerpTreasuryInspect, SerpTreasuryMutate,
 };
 use orml_traits::parameter_type_with_key;
 pub use primitives::{
@@ -102,7 +102,7 @@ impl SortedMembers<AccountId> for Members {
 
 impl orml_oracle::Config for Test {
 	type Event = Event;
-	type OnNewData = ();
+	type OnNewData = Prices;
 	type CombineData = orml_oracle::DefaultCombineData<Self, MinimumCount, ExpiresIn>;
 	type Time = Timestamp;
 	type OracleKey = Key;
@@ -156,7 +156,7 @@ impl pallet_balances::Config for Test {
 }
 
 pub struct MockSerpTreasury;
-impl SerpTreasury<AccountId> for MockSerpTreasury {
+impl SerpTreasuryInspect<AccountId> for MockSerpTreasury {
 	type Balance = Balance;
 	type CurrencyId = CurrencyId;
 
@@ -168,6 +168,15 @@ impl SerpTreasury<AccountId> for MockSerpTreasury {
 		unimplemented!()
 	}
 
+	/// get the minimum supply of a setcurrency - by key
+	fn get_minimum_supply(
+		_currency_id: CurrencyId
+	) -> Balance {
+		unimplemented!()
+	}
+}
+
+impl SerpTreasuryMutate<AccountId> for MockSerpTreasury {
 	fn serp_tes_now() -> DispatchResult {
 		unimplemented!()
 	}
@@ -249,13 +258,6 @@ impl SerpTreasury<AccountId> for MockSerpTreasury {
 		unimplemented!()
 	}
 
-	/// get the minimum supply of a setcurrency - by key
-	fn get_minimum_supply(
-		_currency_id: CurrencyId
-	) -> Balance {
-		unimplemented!()
-	}
-
 	/// issue standard to `who`
 	fn issue_standard(
 		_currency_id: CurrencyId,
@@ -334,6 +336,7 @@ impl module_currencies::Config for Test {
 	type AddressMapping = MockAddressMapping;
 	type EVMBridge = EVMBridge;
 	type SweepOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
+	type DustRemovalWhitelistOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
 	type OnDust = ();
 }
 
@@ -342,8 +345,11 @@ impl module_evm_bridge::Config for Test {
 }
 
 impl module_evm_manager::Config for Test {
+	type Event = Event;
 	type Currency = Balances;
 	type EVMBridge = EVMBridge;
+	type ForeignAssetIdMapping = ();
+	type WeightInfo = ();
 }
 
 parameter_types! {
@@ -509,6 +515,7 @@ pub type OraclePrecompile = crate::OraclePrecompile<
 	MockAddressMapping,
 	EvmCurrencyIdMapping,
 	module_prices::PriorityLockedPriceProvider<Test>,
+	Oracle,
 >;
 pub type ScheduleCallPrecompile = crate::ScheduleCallPrecompile<
 	AccountId,
@@ -580,6 +587,13 @@ parameter_types! {
 	pub SetterFixedPrice: Price = Price::saturating_from_rational(1, 10); // $0.1(10 cents)
 	pub const GetSetUSDId: CurrencyId = SETUSD;
 	pub const SetterCurrencyId: CurrencyId = SETR;
+	pub const MaxSetterBasketLength: u32 = 3;
+	pub const MaxFeeders: u32 = 1;
+	pub const MinimumDexLiquidity: Balance = 1_000_000;
+	pub TwapCurrencyIds: Vec<CurrencyId> = vec![];
+	pub const MaxTwapObservations: u32 = 10;
+	pub const MaxPriceAge: BlockNumber = 100;
+	pub const MaxPriceHistory: u32 = 10;
 }
 
 ord_parameter_types! {
@@ -589,14 +603,25 @@ ord_parameter_types! {
 impl module_prices::Config for Test {
 	type Event = Event;
 	type Source = Oracle;
+	type Sources = (Oracle,);
+	type MaxFeeders = MaxFeeders;
 	type GetSetUSDId = GetSetUSDId;
 	type SetterCurrencyId = SetterCurrencyId;
 	type SetUSDFixedPrice = SetUSDFixedPrice;
 	type SetterFixedPrice = SetterFixedPrice;
+	type MaxSetterBasketLength = MaxSetterBasketLength;
 	type LockOrigin = EnsureSignedBy<One, AccountId>;
+	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
 	type DEX = DexModule;
+	type MinimumDexLiquidity = MinimumDexLiquidity;
 	type Currency = Currencies;
 	type CurrencyIdMapping = EvmCurrencyIdMapping;
+	type TwapCurrencyIds = TwapCurrencyIds;
+	type StableCurrencyIds = StableCurrencyIds;
+	type GetMarketReferenceCurrencyId = GetNativeCurrencyId;
+	type MaxTwapObservations = MaxTwapObservations;
+	type MaxPriceAge = MaxPriceAge;
+	type MaxPriceHistory = MaxPriceHistory;
 	type WeightInfo = ();
 }
 
@@ -679,9 +704,9 @@ frame_support::construct_runtime!(
 		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
 		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
-		Currencies: module_currencies::{Pallet, Call, Event<T>},
+		Currencies: module_currencies::{Pallet, Call, Storage, Event<T>},
 		EVMBridge: module_evm_bridge::{Pallet},
-		EVMManager: module_evm_manager::{Pallet, Storage},
+		EVMManager: module_evm_manager::{Pallet, Call, Storage, Event<T>},
 		NFTModule: module_nft::{Pallet, Call, Event<T>},
 		TransactionPayment: module_transaction_payment::{Pallet, Call, Storage},
 		Prices: module_prices::{Pallet, Storage, Call, Event<T>},
@@ -22,7 +22,9 @@ use crate::precompile::PrecompileOutput;
 use frame_support::{log, sp_runtime::FixedPointNumber};
 use module_evm::{Context, ExitError, ExitSucceed, Precompile};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
-use primitives::CurrencyId;
+use orml_oracle::TimestampedValue;
+use orml_traits::DataProviderExtended;
+use primitives::{CurrencyId, Moment, Price};
 use sp_runtime::RuntimeDebug;
 use sp_std::{fmt::Debug, marker::PhantomData, prelude::*, result};
 
@@ -37,9 +39,15 @@ use module_support::{
 /// `input` data starts with `action`.
 ///
 /// Actions:
-/// - Get price. Rest `input` bytes: `currency_id`.
-pub struct OraclePrecompile<AccountId, AddressMapping, CurrencyIdMapping, PriceProvider>(
-	PhantomData<(AccountId, AddressMapping, CurrencyIdMapping, PriceProvider)>,
+/// - Get price. Rest `input` bytes: `currency_id`. Returns the price of 1 whole
+///   token as an 18 decimals fixed point value.
+/// - Get price with timestamp. Rest `input` bytes: `currency_id`. Returns the
+///   price of 1 whole token as an 18 decimals fixed point value whatever the
+///   decimals of the token, the same as get price, and the timestamp of the
+///   oracle feed. The timestamp is 0 if the price is not the oracle feed
+///   itself, e.g. a fixed, aggregated or DEX implied price.
+pub struct OraclePrecompile<AccountId, AddressMapping, CurrencyIdMapping, PriceProvider, Oracle>(
+	PhantomData<(AccountId, AddressMapping, CurrencyIdMapping, PriceProvider, Oracle)>,
 );
 
 #[primitives_proc_macro::generate_function_selector]
@@ -47,15 +55,17 @@ pub struct OraclePrecompile<AccountId, AddressMapping, CurrencyIdMapping, PriceP
 #[repr(u32)]
 pub enum Action {
 	GetPrice = "getPrice(address)",
+	GetPriceWithTimestamp = "getPriceWithTimestamp(address)",
 }
 
-impl<AccountId, AddressMapping, CurrencyIdMapping, PriceProvider> Precompile
-	for OraclePrecompile<AccountId, AddressMapping, CurrencyIdMapping, PriceProvider>
+impl<AccountId, AddressMapping, CurrencyIdMapping, PriceProvider, Oracle> Precompile
+	for OraclePrecompile<AccountId, AddressMapping, CurrencyIdMapping, PriceProvider, Oracle>
 where
 	AccountId: Debug + Clone,
 	AddressMapping: AddressMappingT<AccountId>,
 	CurrencyIdMapping: CurrencyIdMappingT,
 	PriceProvider: PriceProviderT<CurrencyId>,
+	Oracle: DataProviderExtended<CurrencyId, TimestampedValue<Price, Moment>>,
 {
 	fn execute(
 		input: &[u8],
@@ -103,6 +113,43 @@ where
 					logs: Default::default(),
 				})
 			}
+			Action::GetPriceWithTimestamp => {
+				let currency_id = input.currency_id_at(1)?;
+
+				let maybe_price = PriceProvider::get_price(currency_id);
+				let maybe_decimals = CurrencyIdMapping::decimals(currency_id);
+				let maybe_multiplier = maybe_decimals.and_then(|decimals| 10u128.checked_pow(decimals.into()));
+				let maybe_adjustment_divisor = maybe_decimals
+					.and_then(|decimals| 18u8.checked_sub(decimals))
+					.and_then(|exponent| 10u128.checked_pow(exponent.into()));
+
+				// The price is for 1 basic unit, scale it up to 1 whole token in 18 decimals.
+				// Solidity should handle the situation of price 0.
+				let price = maybe_price
+					.zip(maybe_adjustment_divisor)
+					.and_then(|(price, adjustment_divisor)| price.into_inner().checked_div(adjustment_divisor))
+					.unwrap_or_default();
+
+				// Only return the timestamp of the oracle feed if the price is the feed itself,
+				// converted to 1 basic unit the same way as the prices module does.
+				let timestamp = Oracle::get_no_op(&currency_id)
+					.filter(|feed| {
+						maybe_price.is_some()
+							&& maybe_multiplier
+								.and_then(|multiplier| Price::checked_from_rational(feed.value.into_inner(), multiplier))
+								== maybe_price
+					})
+					.map(|feed| feed.timestamp)
+					.unwrap_or_default();
+
+				log::debug!(target: "evm", "oracle: getPriceWithTimestamp currency_id: {:?}, price: {:?}, timestamp: {:?}", currency_id, price, timestamp);
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: 0,
+					output: Output::default().encode_u128_tuple(price, timestamp.into()),
+					logs: Default::default(),
+				})
+			}
 		}
 	}
 }
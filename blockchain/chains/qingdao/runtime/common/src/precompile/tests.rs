@@ -33,7 +33,7 @@ use crate::precompile::{
 use codec::Encode;
 use frame_support::{assert_noop, assert_ok};
 use hex_literal::hex;
-use module_evm::{Context, ExitError, ExitSucceed, Precompile};
+use module_evm::{Context, ExitError, ExitReason, ExitSucceed, Precompile};
 use module_support::AddressMapping;
 use orml_traits::DataFeeder;
 use primitives::{Balance, PREDEPLOY_ADDRESS_START};
@@ -389,6 +389,88 @@ fn precompile_filter_does_not_work_on_non_system_contracts() {
 // 	});
 // }
 
+#[test]
+fn oracle_precompile_get_price_with_timestamp_should_work() {
+	new_test_ext().execute_with(|| {
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		let price = Price::from(30_000);
+
+		// action + currency_id
+		let mut input = [0u8; 36];
+		// action
+		input[0..4].copy_from_slice(&Into::<u32>::into(oracle::Action::GetPriceWithTimestamp).to_be_bytes());
+		// SERP
+		U256::from_big_endian(serp_evm_address().as_bytes()).to_big_endian(&mut input[4..4 + 32]);
+
+		// no price yet
+		let resp = OraclePrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, [0u8; 64]);
+		assert_eq!(resp.cost, 0);
+
+		assert_ok!(Oracle::feed_value(ALICE, SERP, price));
+
+		// returned price of 1 whole token in 18 decimals + timestamp of the feed
+		let mut expected_output = [0u8; 64];
+		U256::from(price.into_inner()).to_big_endian(&mut expected_output[0..32]);
+		U256::from(1).to_big_endian(&mut expected_output[32..64]);
+
+		let resp = OraclePrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, expected_output);
+		assert_eq!(resp.cost, 0);
+
+		// SETUSD has a fixed price, it is not the oracle feed so the timestamp is 0
+		U256::from_big_endian(setusd_evm_address().as_bytes()).to_big_endian(&mut input[4..4 + 32]);
+
+		let mut expected_output = [0u8; 64];
+		U256::from(Price::saturating_from_integer(1).into_inner()).to_big_endian(&mut expected_output[0..32]);
+
+		let resp = OraclePrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, expected_output);
+		assert_eq!(resp.cost, 0);
+	});
+}
+
+#[test]
+fn oracle_predeploy_contract_get_price_with_timestamp_should_work() {
+	new_test_ext().execute_with(|| {
+		let oracle_address = H160::from_low_u64_be(PREDEPLOY_ADDRESS_START + 2);
+		let price = Price::from(30_000);
+		assert_ok!(Oracle::feed_value(ALICE, SERP, price));
+
+		// getPriceWithTimestamp(address) + SERP
+		let mut input = hex!("eeb9ef62").to_vec();
+		input.extend_from_slice(&[0u8; 12]);
+		input.extend_from_slice(serp_evm_address().as_bytes());
+
+		let result = <<Test as module_evm::Config>::Runner as module_evm::runner::Runner<Test>>::call(
+			alice_evm_addr(),
+			alice_evm_addr(),
+			oracle_address,
+			input,
+			0,
+			1_000_000,
+			0,
+			<Test as module_evm::Config>::config(),
+		)
+		.unwrap();
+
+		let mut expected_output = [0u8; 64];
+		U256::from(price.into_inner()).to_big_endian(&mut expected_output[0..32]);
+		U256::from(1).to_big_endian(&mut expected_output[32..64]);
+
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		assert_eq!(result.value, expected_output.to_vec());
+	});
+}
+
 // #[test]
 // fn schedule_call_precompile_should_work() {
 // 	new_test_ext().execute_with(|| {
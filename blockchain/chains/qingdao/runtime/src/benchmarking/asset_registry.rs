@@ -0,0 +1,55 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AssetRegistry, Origin, Runtime};
+
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use primitives::{AssetMetadata, Balance};
+use sp_std::boxed::Box;
+
+fn foreign_asset_metadata() -> AssetMetadata<Balance> {
+	AssetMetadata {
+		name: b"Foreign Token".to_vec(),
+		symbol: b"FT".to_vec(),
+		decimals: 12,
+		minimal_balance: 1,
+	}
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_asset_registry }
+
+	register_foreign_asset {
+	}: _(RawOrigin::Root, Box::new(foreign_asset_metadata()))
+
+	update_foreign_asset {
+		AssetRegistry::register_foreign_asset(Origin::root(), Box::new(foreign_asset_metadata()))?;
+	}: _(RawOrigin::Root, 0, Box::new(foreign_asset_metadata()))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
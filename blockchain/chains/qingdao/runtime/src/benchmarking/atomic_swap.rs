@@ -0,0 +1,84 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AccountId, AtomicSwap, CurrencyId, MaxSwapDuration, Origin, ProofLimit, Runtime, TokenSymbol};
+
+use super::utils::{dollar, set_balance};
+use frame_benchmarking::account;
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+const SETR: CurrencyId = CurrencyId::Token(TokenSymbol::SETR);
+
+runtime_benchmarks! {
+	{ Runtime, module_atomic_swap }
+
+	create_swap {
+		let source: AccountId = account("source", 0, SEED);
+		let target: AccountId = account("target", 0, SEED);
+		set_balance(SETR, &source, 1_000 * dollar(SETR));
+		let hashed_proof = sp_io::hashing::sha2_256(&[0u8; 32]);
+	}: _(RawOrigin::Signed(source), hashed_proof, target, SETR, 100 * dollar(SETR), MaxSwapDuration::get())
+
+	claim {
+		let c in 0 .. ProofLimit::get();
+
+		let source: AccountId = account("source", 0, SEED);
+		let target: AccountId = account("target", 0, SEED);
+		set_balance(SETR, &source, 1_000 * dollar(SETR));
+		let secret = vec![1u8; c as usize];
+		AtomicSwap::create_swap(
+			Origin::signed(source.clone()),
+			sp_io::hashing::sha2_256(&secret),
+			target.clone(),
+			SETR,
+			100 * dollar(SETR),
+			MaxSwapDuration::get(),
+		)?;
+	}: _(RawOrigin::Signed(target), source, secret)
+
+	refund {
+		let source: AccountId = account("source", 0, SEED);
+		let target: AccountId = account("target", 0, SEED);
+		set_balance(SETR, &source, 1_000 * dollar(SETR));
+		let hashed_proof = sp_io::hashing::sha2_256(&[0u8; 32]);
+		AtomicSwap::create_swap(
+			Origin::signed(source.clone()),
+			hashed_proof,
+			target,
+			SETR,
+			100 * dollar(SETR),
+			MaxSwapDuration::get(),
+		)?;
+		let end_block = frame_system::Pallet::<Runtime>::block_number() + MaxSwapDuration::get();
+		frame_system::Pallet::<Runtime>::set_block_number(end_block);
+	}: _(RawOrigin::Signed(source), hashed_proof)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -142,6 +142,13 @@ runtime_benchmarks! {
 		});
 		assert_eq!(Tokens::free_balance(DNAR, &treasury), dollar(DNAR) + (100 * c) as Balance);
 	}
+
+	set_dust_removal_whitelist {
+		let who: AccountId = account("who", 0, SEED);
+	}: _(RawOrigin::Root, who.clone(), true)
+	verify {
+		assert!(Currencies::is_dust_removal_whitelisted(&who));
+	}
 }
 
 #[cfg(test)]
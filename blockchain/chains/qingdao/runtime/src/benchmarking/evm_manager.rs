@@ -0,0 +1,61 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AccountId, EvmCurrencyIdMapping, Origin, Runtime, EVM};
+
+use frame_benchmarking::whitelisted_caller;
+use frame_support::storage::with_transaction;
+use frame_system::RawOrigin;
+use module_support::CurrencyIdMapping;
+use orml_benchmarking::runtime_benchmarks;
+use sp_core::H160;
+use sp_runtime::TransactionOutcome;
+
+fn deploy_erc20() -> H160 {
+	let code = hex::decode(
+		include!("../../../../../modules/evm-bridge/src/erc20_demo_contract").trim_start_matches("0x"),
+	)
+	.unwrap();
+	let address = H160::from_low_u64_be(EVM::network_contract_index());
+	EVM::create_network_contract(Origin::root(), code, 0, 2_100_000, 10000).unwrap();
+	EVM::deploy_free(Origin::root(), address).unwrap();
+	address
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_evm_manager }
+
+	refresh_erc20_metadata {
+		let caller: AccountId = whitelisted_caller();
+		let address = deploy_erc20();
+		with_transaction(|| {
+			TransactionOutcome::Commit(EvmCurrencyIdMapping::<Runtime>::set_erc20_mapping(address))
+		})?;
+	}: _(RawOrigin::Signed(caller), address)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
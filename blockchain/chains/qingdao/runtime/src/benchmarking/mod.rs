@@ -25,6 +25,8 @@ use sp_runtime::traits::AccountIdConversion;
 pub mod utils;
 
 // module benchmarking
+pub mod asset_registry;
+pub mod atomic_swap;
 pub mod auction_manager;
 pub mod cdp_engine;
 pub mod cdp_treasury;
@@ -34,6 +36,7 @@ pub mod dex;
 // pub mod emergency_shutdown;
 // pub mod evm;
 pub mod evm_accounts;
+pub mod evm_manager;
 pub mod serp_setmint;
 pub mod serp_treasury;
 pub mod prices;
@@ -18,13 +18,27 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CurrencyId, GetSetUSDId, Origin, Price, Prices, Runtime};
+use crate::{
+	BlockNumber, CollateralCurrencyIds, CurrencyId, GetSetUSDId, MaxPriceHistory, MaxSetterBasketLength,
+	MaxTwapObservations, Origin, Price, Prices, Runtime,
+};
+use module_prices::{
+	AggregationMethod, AggregationParams, DeviationThresholds, FedCurrencyIds, PriceHistory, TwapObservations,
+};
 
 use super::utils::feed_price;
+use frame_support::{
+	traits::{OnFinalize, OnInitialize},
+	BoundedVec,
+};
 use frame_system::RawOrigin;
 use orml_benchmarking::runtime_benchmarks;
-use sp_runtime::traits::One;
-use sp_std::vec;
+use primitives::evm::EvmAddress;
+use sp_runtime::{
+	traits::{One, Zero},
+	FixedPointNumber, Permill,
+};
+use sp_std::{convert::TryInto, prelude::*, vec};
 
 const SETUSD: CurrencyId = GetSetUSDId::get();
 
@@ -41,6 +55,74 @@ runtime_benchmarks! {
 		feed_price(vec![(SETUSD, Price::one())])?;
 		Prices::lock_price(Origin::root(), SETUSD)?;
 	}: _(RawOrigin::Root, SETUSD)
+
+	on_initialize {
+		let c in 0 .. CollateralCurrencyIds::get().len() as u32;
+		let currency_ids = CollateralCurrencyIds::get();
+
+		// feed price, and fill the observations up to `MaxTwapObservations`
+		// so that the oldest one is dropped
+		let max_observations = MaxTwapObservations::get();
+		for i in 0 .. c {
+			let currency_id = currency_ids[i as usize];
+			feed_price(vec![(currency_id, Price::one())])?;
+			let observations: BoundedVec<(BlockNumber, Price, Price), MaxTwapObservations> = (0 .. max_observations)
+				.map(|block| (block, Price::saturating_from_integer(block), Price::one()))
+				.collect::<Vec<_>>()
+				.try_into()
+				.unwrap();
+			TwapObservations::<Runtime>::insert(currency_id, observations);
+		}
+	}: {
+		Prices::on_initialize(max_observations);
+	}
+
+	on_finalize {
+		let c in 0 .. CollateralCurrencyIds::get().len() as u32;
+		let currency_ids = CollateralCurrencyIds::get();
+
+		// feed price, and fill the history up to `MaxPriceHistory`
+		// so that the oldest price is dropped
+		for i in 0 .. c {
+			let currency_id = currency_ids[i as usize];
+			feed_price(vec![(currency_id, Price::one())])?;
+			let history: BoundedVec<Price, MaxPriceHistory> =
+				vec![Price::one(); MaxPriceHistory::get() as usize].try_into().unwrap();
+			PriceHistory::<Runtime>::insert(currency_id, history);
+		}
+		// `feed_price` records the feeds, mark them to be recorded again
+		FedCurrencyIds::<Runtime>::put(currency_ids[.. c as usize].to_vec());
+	}: {
+		Prices::on_finalize(1);
+	}
+
+	set_aggregation_params {
+		let params = AggregationParams {
+			method: AggregationMethod::WeightedMean(vec![1].try_into().unwrap()),
+			min_feeders: 1,
+		};
+	}: _(RawOrigin::Root, SETUSD, Some(params))
+
+	set_price_bounds {
+	}: _(RawOrigin::Root, SETUSD, Some((Price::zero(), Price::one())))
+
+	set_peg_deviation_thresholds {
+	}: _(RawOrigin::Root, Some(DeviationThresholds {
+		info: Permill::from_percent(1),
+		warning: Permill::from_percent(3),
+		critical: Permill::from_percent(10),
+	}))
+
+	set_setter_basket {
+		let c in 1 .. MaxSetterBasketLength::get();
+
+		// c - 1 pegs of equal weight and the remainder on the last peg
+		let weight = Permill::from_rational(1, c);
+		let mut basket: Vec<(CurrencyId, Permill)> = (1 .. c)
+			.map(|i| (CurrencyId::Erc20(EvmAddress::from_low_u64_be(i.into())), weight))
+			.collect();
+		basket.push((SETUSD, Permill::from_parts(Permill::one().deconstruct() - weight.deconstruct() * (c - 1))));
+	}: _(RawOrigin::Root, basket)
 }
 
 #[cfg(test)]
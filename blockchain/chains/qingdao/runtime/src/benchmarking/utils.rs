@@ -24,12 +24,12 @@
 use crate::{
 	SetheumOracle, AccountId, Balance, Currencies,
 	CurrencyId, MinimumCount, OperatorMembershipSetheum,
-	Price, Runtime, TokenSymbol,
+	Price, Prices, Runtime, System, TokenSymbol,
 };
 
 use frame_benchmarking::account;
 use frame_support::traits::tokens::fungibles;
-use frame_support::{assert_ok, traits::Contains};
+use frame_support::{assert_ok, traits::{Contains, OnFinalize}};
 use frame_system::RawOrigin;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 use sp_runtime::{
@@ -64,6 +64,8 @@ pub fn feed_price(prices: Vec<(CurrencyId, Price)>) -> DispatchResult {
 		SetheumOracle::feed_values(RawOrigin::Signed(oracle).into(), prices.to_vec())
 			.map_or_else(|e| Err(e.error), |_| Ok(()))?;
 	}
+	// record the feeds, or the prices are stale
+	Prices::on_finalize(System::block_number());
 
 	Ok(())
 }
@@ -542,6 +542,7 @@ impl module_currencies::Config for Runtime {
 	type AddressMapping = EvmAddressMapping<Runtime>;
 	type EVMBridge = EVMBridge;
 	type SweepOrigin = EnsureRootOrOneShuraCouncil;
+	type DustRemovalWhitelistOrigin = EnsureRootOrHalfShuraCouncil;
 	type OnDust = module_currencies::TransferDust<Runtime, TreasuryAccount>;
 }
 
@@ -555,7 +556,7 @@ parameter_types! {
 type SetheumDataProvider = orml_oracle::Instance1;
 impl orml_oracle::Config<SetheumDataProvider> for Runtime {
 	type Event = Event;
-	type OnNewData = ();
+	type OnNewData = Prices;
 	type CombineData = orml_oracle::DefaultCombineData<Runtime, MinimumCount, ExpiresIn, SetheumDataProvider>;
 	type Time = Timestamp;
 	type OracleKey = CurrencyId;
@@ -583,7 +584,7 @@ impl DataFeeder<CurrencyId, Price, AccountId> for AggregatedDataProvider {
 pub struct DustRemovalWhitelist;
 impl Contains<AccountId> for DustRemovalWhitelist {
 	fn contains(a: &AccountId) -> bool {
-		get_all_module_accounts().contains(a)
+		get_all_module_accounts().contains(a) || Currencies::is_dust_removal_whitelisted(a)
 	}
 }
 
@@ -623,6 +624,11 @@ parameter_type_with_key! {
 				}
 			},
 			CurrencyId::Erc20(_) => Balance::max_value(), // not handled by orml-tokens
+			CurrencyId::StableAssetPoolToken(_) => 1, // pool tokens are 18 decimals and minted by the pool
+			CurrencyId::ForeignAsset(_) => {
+				// unregistered foreign assets can't be held
+				AssetRegistry::existential_deposit(*currency_id).unwrap_or_else(Balance::max_value)
+			},
 		}
 	};
 }
@@ -630,7 +636,7 @@ parameter_type_with_key! {
 parameter_types! {
 	pub TreasuryAccount: AccountId = TreasuryPalletId::get().into_account();
 	pub CDPTreasuryAccount: AccountId = CDPTreasuryPalletId::get().into_account();
-	// pub SerpTreasuryAccount: AccountId = SerpTreasuryPalletId::get().into_account();
+	pub SerpTreasuryAccount: AccountId = SerpTreasuryPalletId::get().into_account();
 }
 
 impl orml_tokens::Config for Runtime {
@@ -640,7 +646,7 @@ impl orml_tokens::Config for Runtime {
 	type CurrencyId = CurrencyId;
 	type WeightInfo = weights::orml_tokens::WeightInfo<Runtime>;
 	type ExistentialDeposits = ExistentialDeposits;
-	type OnDust = orml_tokens::TransferDust<Runtime, TreasuryAccount>;
+	type OnDust = orml_tokens::TransferDust<Runtime, SerpTreasuryAccount>;
 	type MaxLocks = MaxLocks;
 	type DustRemovalWhitelist = DustRemovalWhitelist;
 }
@@ -648,19 +654,36 @@ impl orml_tokens::Config for Runtime {
 parameter_types! {
 	pub SetUSDFixedPrice: Price = Price::saturating_from_rational(1, 1); // $1
 	pub SetterFixedPrice: Price = Price::saturating_from_rational(1, 4); // $0.25
+	pub const MaxSetterBasketLength: u32 = 10;
+	pub const MaxFeeders: u32 = 1;
+	pub MinimumDexLiquidity: Balance = 100_000 * dollar(SETUSD);
+	pub const MaxTwapObservations: u32 = 10 * MINUTES;
+	pub const MaxPriceAge: BlockNumber = HOURS;
+	pub const MaxPriceHistory: u32 = 100;
 }
 
 impl module_prices::Config for Runtime {
 	type Event = Event;
 	type Source = AggregatedDataProvider;
+	type Sources = (SetheumOracle,);
+	type MaxFeeders = MaxFeeders;
 	type GetSetUSDId = GetSetUSDId;
 	type SetterCurrencyId = SetterCurrencyId;
 	type SetUSDFixedPrice = SetUSDFixedPrice;
 	type SetterFixedPrice = SetterFixedPrice;
+	type MaxSetterBasketLength = MaxSetterBasketLength;
 	type LockOrigin = EnsureRootOrTwoThirdsFinancialCouncil;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
 	type DEX = Dex;
+	type MinimumDexLiquidity = MinimumDexLiquidity;
 	type Currency = Currencies;
 	type CurrencyIdMapping = EvmCurrencyIdMapping<Runtime>;
+	type TwapCurrencyIds = CollateralCurrencyIds;
+	type StableCurrencyIds = StableCurrencyIds;
+	type GetMarketReferenceCurrencyId = GetNativeCurrencyId;
+	type MaxTwapObservations = MaxTwapObservations;
+	type MaxPriceAge = MaxPriceAge;
+	type MaxPriceHistory = MaxPriceHistory;
 	type WeightInfo = weights::module_prices::WeightInfo<Runtime>;
 }
 
@@ -677,6 +700,30 @@ impl module_transaction_pause::Config for Runtime {
 	type WeightInfo = weights::module_transaction_pause::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub const MaxAssetMetadataLength: u32 = 32;
+}
+
+impl module_asset_registry::Config for Runtime {
+	type Event = Event;
+	type RegisterOrigin = EnsureRootOrHalfFinancialCouncil;
+	type MaxMetadataLength = MaxAssetMetadataLength;
+	type WeightInfo = weights::module_asset_registry::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxSwapDuration: BlockNumber = 7 * DAYS;
+	pub const ProofLimit: u32 = 1024;
+}
+
+impl module_atomic_swap::Config for Runtime {
+	type Event = Event;
+	type MultiCurrency = Currencies;
+	type MaxSwapDuration = MaxSwapDuration;
+	type ProofLimit = ProofLimit;
+	type WeightInfo = weights::module_atomic_swap::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub MinimumIncrementSize: Rate = Rate::saturating_from_rational(2, 100); // 2%
 	pub const AuctionTimeToClose: BlockNumber = 15 * MINUTES;
@@ -857,6 +904,8 @@ parameter_types! {
 
 // parameter_types! {
 // 	pub const MaxAirdropListSize: usize = 250;
+// 	pub const MaxMerkleProofLength: u32 = 32;
+// 	pub const SweepTip: Permill = Permill::from_parts(1_000); // 0.1%
 // }
 
 // impl module_airdrop::Config for Runtime {
@@ -866,6 +915,8 @@ parameter_types! {
 // 	type FundingOrigin = TreasuryAccount;
 // 	type DropOrigin = EnsureRootOrTwoThirdsShuraCouncil;
 // 	type PalletId = AirdropPalletId;
+// 	type MaxMerkleProofLength = MaxMerkleProofLength;
+// 	type SweepTip = SweepTip;
 // }
 
 parameter_types! {
@@ -983,8 +1034,11 @@ impl module_evm_accounts::Config for Runtime {
 }
 
 impl module_evm_manager::Config for Runtime {
+	type Event = Event;
 	type Currency = Balances;
 	type EVMBridge = EVMBridge;
+	type ForeignAssetIdMapping = module_asset_registry::AssetIdMaps<Runtime>;
+	type WeightInfo = weights::module_evm_manager::WeightInfo<Runtime>;
 }
 
 #[cfg(feature = "with-ethereum-compatibility")]
@@ -1018,6 +1072,7 @@ pub type OraclePrecompile = runtime_common::OraclePrecompile<
 	EvmAddressMapping<Runtime>,
 	EvmCurrencyIdMapping<Runtime>,
 	module_prices::RealTimePriceProvider<Runtime>,
+	SetheumOracle,
 >;
 pub type ScheduleCallPrecompile = runtime_common::ScheduleCallPrecompile<
 	AccountId,
@@ -1578,7 +1633,7 @@ construct_runtime!(
 
 		// Tokens, Fees & Related
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>} = 36,
-		Currencies: module_currencies::{Pallet, Call, Event<T>} = 37,
+		Currencies: module_currencies::{Pallet, Call, Storage, Event<T>} = 37,
 		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>} = 38,
 		TransactionPayment: module_transaction_payment::{Pallet, Call, Storage} = 39,
 		TransactionPause: module_transaction_pause::{Pallet, Call, Storage, Event<T>} = 40,
@@ -1591,7 +1646,7 @@ construct_runtime!(
 		EVM: module_evm::{Pallet, Config<T>, Call, Storage, Event<T>} = 43,
 		EvmAccounts: module_evm_accounts::{Pallet, Call, Storage, Event<T>} = 44,
 		EVMBridge: module_evm_bridge::{Pallet} = 45,
-		EvmManager: module_evm_manager::{Pallet, Storage} = 46,
+		EvmManager: module_evm_manager::{Pallet, Call, Storage, Event<T>} = 46,
 
 		// Consensus
 		Authorship: pallet_authorship::{Pallet, Call, Storage, Inherent} = 47,
@@ -1603,9 +1658,21 @@ construct_runtime!(
 		Offences: pallet_offences::{Pallet, Storage, Event} = 53,
 		ImOnline: pallet_im_online::{Pallet, Call, Storage, Event<T>, ValidateUnsigned, Config<T>} = 54,
 		AuthorityDiscovery: pallet_authority_discovery::{Pallet, Config} = 55,
+
+		// Asset Registry
+		AssetRegistry: module_asset_registry::{Pallet, Call, Storage, Event<T>} = 56,
+
+		// Atomic Swap
+		AtomicSwap: module_atomic_swap::{Pallet, Call, Storage, Event<T>} = 57,
 	}
 );
 
+parameter_types! {
+	/// The (old, new) currency ids to move the prices module's storage between
+	/// on the next runtime upgrade.
+	pub PricesCurrencyIdRenames: Vec<(CurrencyId, CurrencyId)> = vec![];
+}
+
 pub struct OnRuntimeUpgrade;
 impl frame_support::traits::OnRuntimeUpgrade for OnRuntimeUpgrade {
 	fn on_runtime_upgrade() -> u64 {
@@ -1693,7 +1760,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPallets,
-	OnRuntimeUpgrade,
+	(OnRuntimeUpgrade, module_prices::migrations::RenameCurrencyIds<Runtime, PricesCurrencyIdRenames>),
 >;
 
 impl frame_system::offchain::SigningTypes for Runtime {
@@ -1876,6 +1943,82 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_prices_rpc_runtime_api::PricesApi<Block> for Runtime {
+		fn get_moving_average_price(currency_id: CurrencyId, periods: u32) -> Option<Price> {
+			Prices::get_moving_average_price(currency_id, periods)
+		}
+	}
+
+	impl module_currencies_rpc_runtime_api::CurrenciesApi<Block, AccountId, Balance> for Runtime {
+		fn get_account_portfolio(who: AccountId, erc20_offset: u32, erc20_limit: u32) -> Vec<(CurrencyId, Balance)> {
+			use orml_traits::MultiCurrency;
+			use sp_runtime::traits::Zero;
+
+			// The ERC20 mirrors queried in one call, each one is an EVM call.
+			const MAX_ERC20_PAGE_SIZE: u32 = 50;
+
+			let native_currency_id = GetNativeCurrencyId::get();
+			let mut portfolio = vec![(native_currency_id, Currencies::free_balance(native_currency_id, &who))];
+			portfolio.extend(
+				orml_tokens::Accounts::<Runtime>::iter_prefix(&who).map(|(currency_id, account)| (currency_id, account.free)),
+			);
+			portfolio.extend(
+				module_evm_manager::CurrencyIdMap::<Runtime>::iter_values()
+					.skip(erc20_offset as usize)
+					.take(erc20_limit.min(MAX_ERC20_PAGE_SIZE) as usize)
+					.map(|info| {
+						let currency_id = CurrencyId::Erc20(info.address);
+						(currency_id, Currencies::free_balance(currency_id, &who))
+					}),
+			);
+			portfolio.retain(|(_, balance)| !balance.is_zero());
+			portfolio
+		}
+
+		fn get_token_list() -> Vec<module_currencies_rpc_runtime_api::TokenMetadata> {
+			use module_currencies_rpc_runtime_api::TokenMetadata;
+			use primitives::{currency::TokenInfo, evm::EvmAddress};
+			use sp_std::convert::TryFrom;
+
+			let stable_currency_ids = StableCurrencyIds::get();
+			let token_metadata = |currency_id: CurrencyId, name: Vec<u8>, symbol: Vec<u8>, decimals: u8| TokenMetadata {
+				currency_id,
+				name,
+				symbol,
+				decimals,
+				evm_address: EvmAddress::try_from(currency_id).ok(),
+				is_stable: stable_currency_ids.contains(&currency_id),
+				is_dex_share: currency_id.is_dex_share_currency_id(),
+			};
+
+			let mut tokens: Vec<TokenMetadata> = TokenSymbol::get_info()
+				.into_iter()
+				.filter_map(|(symbol, _)| TokenSymbol::try_from(symbol).ok())
+				.map(|symbol| {
+					let currency_id = CurrencyId::Token(symbol);
+					token_metadata(
+						currency_id,
+						currency_id.name().unwrap_or_default().as_bytes().to_vec(),
+						currency_id.symbol().unwrap_or_default().as_bytes().to_vec(),
+						currency_id.decimals().unwrap_or_default(),
+					)
+				})
+				.collect();
+			tokens.extend(module_evm_manager::CurrencyIdMap::<Runtime>::iter_values().map(|info| {
+				token_metadata(CurrencyId::Erc20(info.address), info.name, info.symbol, info.decimals)
+			}));
+			tokens.extend(module_asset_registry::AssetMetadatas::<Runtime>::iter().map(|(foreign_asset_id, metadata)| {
+				token_metadata(
+					CurrencyId::ForeignAsset(foreign_asset_id),
+					metadata.name,
+					metadata.symbol,
+					metadata.decimals,
+				)
+			}));
+			tokens
+		}
+	}
+
 	impl frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce> for Runtime {
 		fn account_nonce(account: AccountId) -> Nonce {
 			System::account_nonce(account)
@@ -2011,6 +2154,8 @@ impl_runtime_apis! {
 			// orml_list_benchmark!(list, extra, serp_treasury, benchmarking::serp_treasury);
 			// orml_list_benchmark!(list, extra, cdp_treasury, benchmarking::cdp_treasury);
 			orml_list_benchmark!(list, extra, module_transaction_pause, benchmarking::transaction_pause);
+			orml_list_benchmark!(list, extra, module_asset_registry, benchmarking::asset_registry);
+			orml_list_benchmark!(list, extra, module_atomic_swap, benchmarking::atomic_swap);
 			orml_list_benchmark!(list, extra, module_transaction_payment, benchmarking::transaction_payment);
 			orml_list_benchmark!(list, extra, module_prices, benchmarking::prices);
 			// orml_list_benchmark!(list, extra, dex_oracle, benchmarking::dex_oracle);
@@ -2069,9 +2214,12 @@ impl_runtime_apis! {
 			// orml_add_benchmark!(params, batches, serp_treasury, benchmarking::serp_treasury);
 			// orml_add_benchmark!(params, batches, cdp_treasury, benchmarking::cdp_treasury);
 			orml_add_benchmark!(params, batches, module_transaction_pause, benchmarking::transaction_pause);
+			orml_add_benchmark!(params, batches, module_asset_registry, benchmarking::asset_registry);
+			orml_add_benchmark!(params, batches, module_atomic_swap, benchmarking::atomic_swap);
 			orml_add_benchmark!(params, batches, module_transaction_payment, benchmarking::transaction_payment);
 			// orml_add_benchmark!(params, batches, dex_oracle, benchmarking::dex_oracle);
 			orml_add_benchmark!(params, batches, module_evm_accounts, benchmarking::evm_accounts);
+			orml_add_benchmark!(params, batches, module_evm_manager, benchmarking::evm_manager);
 			orml_add_benchmark!(params, batches, module_currencies, benchmarking::currencies);
 
 			orml_add_benchmark!(params, batches, orml_tokens, benchmarking::tokens);
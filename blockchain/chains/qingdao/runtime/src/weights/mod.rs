@@ -22,6 +22,8 @@
 #![allow(clippy::unnecessary_cast)]
 
 // pub mod dex_oracle;
+pub mod module_asset_registry;
+pub mod module_atomic_swap;
 pub mod module_auction_manager;
 pub mod module_cdp_engine;
 pub mod module_cdp_treasury;
@@ -30,6 +32,7 @@ pub mod module_dex;
 pub mod emergency_shutdown;
 pub mod module_evm;
 pub mod module_evm_accounts;
+pub mod module_evm_manager;
 pub mod serp_setmint;
 pub mod serp_treasury;
 pub mod module_nft;
@@ -23,6 +23,15 @@
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 3.0.0
 //! DATE: 2021-07-19, STEPS: `[50, ]`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 128
+//!
+//! The weights of these functions are PLACEHOLDER ESTIMATES, NOT BENCHMARK OUTPUT,
+//! re-run the benchmark CLI to replace them with measured weights:
+//!   - `on_initialize`
+//!   - `set_aggregation_params`
+//!   - `set_price_bounds`
+//!   - `set_setter_basket`
+//!   - `on_finalize`
+//!   - `set_peg_deviation_thresholds`
 
 // Executed Command:
 // target/release/setheum-node
@@ -58,4 +67,37 @@ impl<T: frame_system::Config> module_prices::WeightInfo for WeightInfo<T> {
 		(24_114_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn on_initialize(c: u32, ) -> Weight {
+		(4_381_000 as Weight)
+			.saturating_add((38_460_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads((12 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+	}
+	fn on_finalize(c: u32, ) -> Weight {
+		(3_412_000 as Weight)
+			.saturating_add((31_850_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((10 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(c as Weight)))
+	}
+	fn set_aggregation_params() -> Weight {
+		(28_912_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_price_bounds() -> Weight {
+		(22_105_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_peg_deviation_thresholds() -> Weight {
+		(20_873_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_setter_basket(c: u32, ) -> Weight {
+		(23_716_000 as Weight)
+			.saturating_add((297_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
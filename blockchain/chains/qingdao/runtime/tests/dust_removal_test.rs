@@ -0,0 +1,105 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Tests to make sure that module accounts and the accounts whitelisted by
+//! governance are never reaped by dust removal.
+
+use frame_support::{assert_ok, traits::Contains};
+use orml_traits::MultiCurrency;
+use setheum_runtime::{
+	cent, AccountId, Currencies, CurrencyId, DustRemovalWhitelist, Origin, Runtime, SerpTreasuryAccount, System,
+	TokenSymbol, Tokens, TreasuryAccount,
+};
+
+const SETUSD: CurrencyId = CurrencyId::Token(TokenSymbol::SETUSD);
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::default()
+		.build_storage::<Runtime>()
+		.unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+/// Leave `who` with 1 less than the existential deposit of SETUSD.
+fn leave_dust(who: &AccountId) {
+	let existential_deposit = 10 * cent(SETUSD);
+	let dest = AccountId::new([9u8; 32]);
+	assert_ok!(<Tokens as MultiCurrency<AccountId>>::deposit(
+		SETUSD,
+		who,
+		2 * existential_deposit
+	));
+	assert_ok!(<Tokens as MultiCurrency<AccountId>>::transfer(
+		SETUSD,
+		who,
+		&dest,
+		existential_deposit + 1
+	));
+}
+
+#[test]
+fn user_accounts_are_reaped_by_dust_removal() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::new([1u8; 32]);
+		assert!(!DustRemovalWhitelist::contains(&alice));
+
+		leave_dust(&alice);
+		assert_eq!(Tokens::free_balance(SETUSD, &alice), 0);
+		assert_eq!(
+			Tokens::free_balance(SETUSD, &SerpTreasuryAccount::get()),
+			10 * cent(SETUSD) - 1
+		);
+	});
+}
+
+#[test]
+fn module_accounts_are_not_reaped_by_dust_removal() {
+	new_test_ext().execute_with(|| {
+		leave_dust(&TreasuryAccount::get());
+		assert_eq!(
+			Tokens::free_balance(SETUSD, &TreasuryAccount::get()),
+			10 * cent(SETUSD) - 1
+		);
+	});
+}
+
+#[test]
+fn governed_accounts_are_not_reaped_by_dust_removal() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::new([1u8; 32]);
+		assert_ok!(Currencies::set_dust_removal_whitelist(Origin::root(), alice.clone(), true));
+		assert!(DustRemovalWhitelist::contains(&alice));
+
+		leave_dust(&alice);
+		assert_eq!(Tokens::free_balance(SETUSD, &alice), 10 * cent(SETUSD) - 1);
+
+		// once removed from the whitelist the account is reaped again
+		assert_ok!(Currencies::set_dust_removal_whitelist(Origin::root(), alice.clone(), false));
+		assert_ok!(<Tokens as MultiCurrency<AccountId>>::transfer(
+			SETUSD,
+			&alice,
+			&AccountId::new([9u8; 32]),
+			1
+		));
+		assert_eq!(Tokens::free_balance(SETUSD, &alice), 0);
+	});
+}
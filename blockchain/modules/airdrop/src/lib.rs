@@ -26,6 +26,15 @@
 //! acccounts in the airdrops from an update origin. 
 //! The module for distributing Setheum Airdrops,
 //! it will be used for the Setheum IAE (Initial Airdrop Event).
+//!
+//! Large airdrops can be made claimable instead: the `DropOrigin` posts the
+//! merkle root of the `(account, amount)` allocations of a drop, and every
+//! beneficiary claims their own allocation with a merkle proof.
+//! The allocations left unclaimed when a merkle drop expires can be swept
+//! back to the treasury by anyone, for a small tip.
+//!
+//! Every airdrop is paid from the `DropBudget` of its currency, which is
+//! topped up by funding the airdrop treasury.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -34,15 +43,35 @@ use frame_support::{pallet_prelude::*, transactional, PalletId, traits::Get};
 use frame_system::pallet_prelude::*;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 use primitives::{Balance, CurrencyId};
+use sp_core::{hashing::blake2_256, H256};
 use sp_std::vec::Vec;
-use sp_runtime::traits::AccountIdConversion;
+use sp_runtime::{traits::{AccountIdConversion, Saturating}, Permill, RuntimeDebug};
 
 mod mock;
+mod tests;
 
 pub use module::*;
 
 type BalanceOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<<T as frame_system::Config>::AccountId>>::Balance;
 
+/// Merkle Drop ID
+pub type MerkleDropId = u32;
+
+/// A claimable airdrop committed to by the merkle root of its allocations.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub struct MerkleDrop<BlockNumber> {
+	/// The airdrop currency type.
+	pub currency_id: CurrencyId,
+	/// The merkle root of the `(account, amount)` allocations.
+	pub merkle_root: H256,
+	/// The total amount of all allocations.
+	pub total_allocation: Balance,
+	/// The amount claimed so far.
+	pub claimed: Balance,
+	/// The block from which the allocations can no longer be claimed.
+	pub expires_at: BlockNumber,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -68,6 +97,14 @@ pub mod module {
 		/// The Airdrop module pallet id, keeps airdrop funds.
 		type PalletId: Get<PalletId>;
 
+		#[pallet::constant]
+		/// The maximum length of a merkle proof, bounds the depth of a merkle drop tree.
+		type MaxMerkleProofLength: Get<u32>;
+
+		#[pallet::constant]
+		/// The ratio of the unclaimed allocations of an expired merkle drop
+		/// paid to the caller sweeping it back to the treasury.
+		type SweepTip: Get<Permill>;
 	}
 
 	#[pallet::error]
@@ -76,6 +113,22 @@ pub mod module {
 		DuplicateAccounts,
 		// The airdrop list is over the max size limit `MaxAirdropListSize`
 		OverSizedAirdropList,
+		// The merkle drop does not exist
+		MerkleDropNotFound,
+		// The allocation has already been claimed
+		AlreadyClaimed,
+		// The merkle proof is over `MaxMerkleProofLength` or does not prove the allocation
+		InvalidMerkleProof,
+		// The claims exceed the total allocation of the merkle drop
+		ExceedTotalAllocation,
+		// The airdrop exceeds the remaining `DropBudget` of the currency
+		BudgetExceeded,
+		// The merkle drop has expired
+		MerkleDropExpired,
+		// The merkle drop has not expired yet
+		MerkleDropNotExpired,
+		// The expiry of the merkle drop is not in the future
+		InvalidExpiry,
 	}
 
 	#[pallet::event]
@@ -92,9 +145,78 @@ pub mod module {
 			funder: T::AccountId,
 			currency_id: CurrencyId,
 			amount: BalanceOf<T>
+		},
+		/// Drop Airdrop to a beneficiary of an airdrop batch
+		AirdropBatchItem {
+			currency_id: CurrencyId,
+			who: T::AccountId,
+			amount: Balance
+		},
+		/// Skip a beneficiary of an airdrop batch that could not receive the airdrop
+		AirdropBatchItemFailed {
+			currency_id: CurrencyId,
+			who: T::AccountId,
+			amount: Balance,
+			error: DispatchError
+		},
+		/// Complete an airdrop batch
+		AirdropBatch {
+			currency_id: CurrencyId,
+			succeeded: u32,
+			failed: u32
+		},
+		/// Create a claimable Merkle Drop
+		MerkleDropCreated {
+			drop_id: MerkleDropId,
+			currency_id: CurrencyId,
+			merkle_root: H256,
+			total_allocation: Balance
+		},
+		/// Claim an allocation of a Merkle Drop
+		Claimed {
+			drop_id: MerkleDropId,
+			who: T::AccountId,
+			amount: Balance
+		},
+		/// Sweep the unclaimed allocations of an expired Merkle Drop back to the treasury
+		MerkleDropSwept {
+			drop_id: MerkleDropId,
+			who: T::AccountId,
+			unclaimed: Balance,
+			tip: Balance
 		}
 	}
 
+	/// The remaining budget of the airdrop treasury for a currency.
+	///
+	/// DropBudget: map CurrencyId => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn drop_budget)]
+	pub type DropBudget<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Balance, ValueQuery>;
+
+	/// The next merkle drop id.
+	///
+	/// NextMerkleDropId: MerkleDropId
+	#[pallet::storage]
+	#[pallet::getter(fn next_merkle_drop_id)]
+	pub type NextMerkleDropId<T: Config> = StorageValue<_, MerkleDropId, ValueQuery>;
+
+	/// The claimable merkle drops.
+	///
+	/// MerkleDrops: map MerkleDropId => Option<MerkleDrop>
+	#[pallet::storage]
+	#[pallet::getter(fn merkle_drops)]
+	pub type MerkleDrops<T: Config> =
+		StorageMap<_, Twox64Concat, MerkleDropId, MerkleDrop<T::BlockNumber>, OptionQuery>;
+
+	/// The accounts that have claimed their allocation of a merkle drop.
+	///
+	/// Claimed: double_map MerkleDropId, AccountId => bool
+	#[pallet::storage]
+	#[pallet::getter(fn claimed)]
+	pub type Claimed<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, MerkleDropId, Twox64Concat, T::AccountId, bool, ValueQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(PhantomData<T>);
 
@@ -118,13 +240,24 @@ pub mod module {
 		) -> DispatchResult {
 			T::DropOrigin::ensure_origin(origin)?;
 
-			T::MultiCurrency::transfer(currency_id, &T::FundingOrigin::get(), &Self::account_id(), amount)?;
-			
-			Self::deposit_event(Event::FundAirdropTreasury {
-				funder: T::FundingOrigin::get(),
-				currency_id,
-				amount
-			});
+			Self::do_fund_airdrop(T::FundingOrigin::get(), currency_id, amount)?;
+			Ok(())
+		}
+
+		/// Fund Airdrop Treasury from the caller, topping up the `DropBudget`.
+		///
+		/// - `currency_id`: `CurrencyId` funding currency type.
+		/// - `amount`: `BalanceOf<T>` funding amounts.
+		#[pallet::weight(100_000_000 as Weight)]
+		#[transactional]
+		pub fn fund_airdrop(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_fund_airdrop(who, currency_id, amount)?;
 			Ok(())
 		}
 
@@ -151,6 +284,199 @@ pub mod module {
 			Self::do_make_airdrop(currency_id, airdrop_list)?;
 			Ok(())
 		}
+
+		/// Make Airdrop to a batch of beneficiaries, skipping the beneficiaries
+		/// that cannot receive it instead of aborting the whole batch.
+		///
+		/// The dispatch origin of this call must be `DropOrigin`.
+		///
+		/// - `currency_id`: `CurrencyId` airdrop currency type.
+		/// - `airdrop_list`: airdrop accounts and respective amounts.
+		#[pallet::weight((
+			(100_000_000 as Weight).saturating_add((10_000_000 as Weight).saturating_mul(airdrop_list.len() as Weight)),
+			DispatchClass::Operational
+		))]
+		#[transactional]
+		pub fn make_airdrop_batch(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			airdrop_list: Vec<(T::AccountId, Balance)>,
+		) -> DispatchResult {
+			T::DropOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				airdrop_list.len() <= T::MaxAirdropListSize::get(),
+				Error::<T>::OverSizedAirdropList,
+			);
+
+			let mut succeeded: u32 = 0;
+			let mut failed: u32 = 0;
+			for (i, (who, amount)) in airdrop_list.iter().enumerate() {
+				let result = if airdrop_list[..i].iter().any(|(other, _)| other == who) {
+					Err(Error::<T>::DuplicateAccounts.into())
+				} else {
+					Self::do_airdrop_batch_item(currency_id, who, *amount)
+				};
+
+				match result {
+					Ok(()) => {
+						succeeded += 1;
+						Self::deposit_event(Event::AirdropBatchItem {
+							currency_id,
+							who: who.clone(),
+							amount: *amount
+						});
+					}
+					Err(error) => {
+						failed += 1;
+						Self::deposit_event(Event::AirdropBatchItemFailed {
+							currency_id,
+							who: who.clone(),
+							amount: *amount,
+							error
+						});
+					}
+				}
+			}
+
+			Self::deposit_event(Event::AirdropBatch { currency_id, succeeded, failed });
+			Ok(())
+		}
+
+		/// Create a claimable Merkle Drop of allocations committed to by `merkle_root`.
+		///
+		/// The dispatch origin of this call must be `DropOrigin`.
+		///
+		/// - `currency_id`: `CurrencyId` airdrop currency type.
+		/// - `merkle_root`: the merkle root of the `(account, amount)` allocations.
+		/// - `total_allocation`: the total amount of all allocations.
+		/// - `expires_at`: the block from which the allocations can no longer be claimed.
+		#[pallet::weight((100_000_000 as Weight, DispatchClass::Operational))]
+		#[transactional]
+		pub fn create_merkle_drop(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			merkle_root: H256,
+			total_allocation: Balance,
+			expires_at: T::BlockNumber,
+		) -> DispatchResult {
+			T::DropOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				expires_at > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::InvalidExpiry,
+			);
+			Self::use_budget(currency_id, total_allocation)?;
+
+			let drop_id = NextMerkleDropId::<T>::mutate(|id| {
+				let current = *id;
+				*id = id.saturating_add(1);
+				current
+			});
+			MerkleDrops::<T>::insert(
+				drop_id,
+				MerkleDrop {
+					currency_id,
+					merkle_root,
+					total_allocation,
+					claimed: 0,
+					expires_at,
+				},
+			);
+
+			Self::deposit_event(Event::MerkleDropCreated {
+				drop_id,
+				currency_id,
+				merkle_root,
+				total_allocation
+			});
+			Ok(())
+		}
+
+		/// Claim the allocation of the caller in a Merkle Drop.
+		///
+		/// - `drop_id`: the merkle drop id.
+		/// - `amount`: the allocated amount.
+		/// - `proof`: the merkle proof of `(caller, amount)`.
+		#[pallet::weight(100_000_000 as Weight)]
+		#[transactional]
+		pub fn claim(
+			origin: OriginFor<T>,
+			drop_id: MerkleDropId,
+			amount: Balance,
+			proof: Vec<H256>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				proof.len() <= T::MaxMerkleProofLength::get() as usize,
+				Error::<T>::InvalidMerkleProof,
+			);
+			ensure!(!Self::claimed(drop_id, &who), Error::<T>::AlreadyClaimed);
+
+			MerkleDrops::<T>::try_mutate(drop_id, |maybe_drop| -> DispatchResult {
+				let merkle_drop = maybe_drop.as_mut().ok_or(Error::<T>::MerkleDropNotFound)?;
+				ensure!(
+					frame_system::Pallet::<T>::block_number() < merkle_drop.expires_at,
+					Error::<T>::MerkleDropExpired,
+				);
+
+				let leaf = Self::merkle_leaf(&who, amount);
+				ensure!(
+					Self::verify_merkle_proof(merkle_drop.merkle_root, leaf, &proof),
+					Error::<T>::InvalidMerkleProof,
+				);
+
+				let claimed = merkle_drop.claimed.saturating_add(amount);
+				ensure!(claimed <= merkle_drop.total_allocation, Error::<T>::ExceedTotalAllocation);
+				merkle_drop.claimed = claimed;
+
+				T::MultiCurrency::transfer(merkle_drop.currency_id, &Self::account_id(), &who, amount)
+			})?;
+			Claimed::<T>::insert(drop_id, &who, true);
+
+			Self::deposit_event(Event::Claimed { drop_id, who, amount });
+			Ok(())
+		}
+
+		/// Sweep the unclaimed allocations of an expired Merkle Drop back to
+		/// the treasury, the caller is paid `SweepTip` of them.
+		///
+		/// The `Claimed` entries of the drop are kept, removing them is unbounded
+		/// and drop ids are never reused.
+		///
+		/// - `drop_id`: the merkle drop id.
+		#[pallet::weight(100_000_000 as Weight)]
+		#[transactional]
+		pub fn sweep_expired(origin: OriginFor<T>, drop_id: MerkleDropId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let merkle_drop = Self::merkle_drops(drop_id).ok_or(Error::<T>::MerkleDropNotFound)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= merkle_drop.expires_at,
+				Error::<T>::MerkleDropNotExpired,
+			);
+
+			let unclaimed = merkle_drop.total_allocation.saturating_sub(merkle_drop.claimed);
+			let tip = T::SweepTip::get().mul_floor(unclaimed);
+			T::MultiCurrency::transfer(merkle_drop.currency_id, &Self::account_id(), &who, tip)?;
+			T::MultiCurrency::transfer(
+				merkle_drop.currency_id,
+				&Self::account_id(),
+				&T::FundingOrigin::get(),
+				unclaimed.saturating_sub(tip),
+			)?;
+
+			MerkleDrops::<T>::remove(drop_id);
+
+			Self::deposit_event(Event::MerkleDropSwept {
+				drop_id,
+				who,
+				unclaimed,
+				tip
+			});
+			Ok(())
+		}
 	}
 }
 
@@ -160,6 +486,51 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_account()
 	}
 
+	/// The merkle leaf of an allocation of `amount` to `who`.
+	pub fn merkle_leaf(who: &T::AccountId, amount: Balance) -> H256 {
+		H256::from(blake2_256(&(who, amount).encode()))
+	}
+
+	/// Verify `proof` that `leaf` is in the merkle tree of `root`,
+	/// each pair of nodes is hashed in sorted order.
+	pub fn verify_merkle_proof(root: H256, leaf: H256, proof: &[H256]) -> bool {
+		let computed_root = proof.iter().fold(leaf, |node, sibling| {
+			if node <= *sibling {
+				H256::from(blake2_256(&[node.as_bytes(), sibling.as_bytes()].concat()))
+			} else {
+				H256::from(blake2_256(&[sibling.as_bytes(), node.as_bytes()].concat()))
+			}
+		});
+		computed_root == root
+	}
+
+	fn do_fund_airdrop(funder: T::AccountId, currency_id: CurrencyId, amount: Balance) -> DispatchResult {
+		T::MultiCurrency::transfer(currency_id, &funder, &Self::account_id(), amount)?;
+		DropBudget::<T>::mutate(currency_id, |budget| *budget = budget.saturating_add(amount));
+
+		Self::deposit_event(Event::FundAirdropTreasury {
+			funder,
+			currency_id,
+			amount
+		});
+		Ok(())
+	}
+
+	/// Deduct `amount` from the `DropBudget` of `currency_id`.
+	fn use_budget(currency_id: CurrencyId, amount: Balance) -> DispatchResult {
+		DropBudget::<T>::try_mutate(currency_id, |budget| -> DispatchResult {
+			*budget = budget.checked_sub(amount).ok_or(Error::<T>::BudgetExceeded)?;
+			Ok(())
+		})
+	}
+
+	/// Drop `amount` to `who` from the `DropBudget`, nothing is changed on failure.
+	#[transactional]
+	fn do_airdrop_batch_item(currency_id: CurrencyId, who: &T::AccountId, amount: Balance) -> DispatchResult {
+		Self::use_budget(currency_id, amount)?;
+		T::MultiCurrency::transfer(currency_id, &Self::account_id(), who, amount)
+	}
+
 	fn do_make_airdrop(currency_id: CurrencyId, airdrop_list: Vec<(T::AccountId, Balance)>) -> DispatchResult {
 
 		// Make sure only unique accounts receive Airdrop
@@ -172,6 +543,11 @@ impl<T: Config> Pallet<T> {
             Error::<T>::DuplicateAccounts,
         );
 
+		let total_amount = airdrop_list
+			.iter()
+			.fold(0 as Balance, |total, (_, amount)| total.saturating_add(*amount));
+		Self::use_budget(currency_id, total_amount)?;
+
 		for (beneficiary, amount) in airdrop_list.iter() {
 			T::MultiCurrency::transfer(currency_id, &Self::account_id(), beneficiary, *amount)?;
 		}
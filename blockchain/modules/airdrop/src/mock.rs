@@ -39,6 +39,9 @@ pub type BlockNumber = u64;
 pub const TREASURY: AccountId = AccountId32::new([0u8; 32]);
 pub const ALICE: AccountId = AccountId32::new([2u8; 32]);
 pub const BOB: AccountId = AccountId32::new([3u8; 32]);
+pub const CHARLIE: AccountId = AccountId32::new([4u8; 32]);
+pub const DAVE: AccountId = AccountId32::new([5u8; 32]);
+pub const EVE: AccountId = AccountId32::new([6u8; 32]);
 pub const SETR: CurrencyId = CurrencyId::Token(TokenSymbol::SETR);
 pub const SETUSD: CurrencyId = CurrencyId::Token(TokenSymbol::SETUSD);
 pub const SEE: CurrencyId = CurrencyId::Token(TokenSymbol::SEE);
@@ -109,11 +112,13 @@ parameter_types! {
 	pub const GetHelpCurrencyId: CurrencyId = HELP;  // Setheum native currency ticker is SEE/
 	pub const AirdropPalletId: PalletId = PalletId(*b"set/drop");
 	pub const MaxAirdropListSize: usize = 4;
+	pub const MaxMerkleProofLength: u32 = 8;
+	pub const SweepTip: Permill = Permill::from_percent(10);
 }
 
 ord_parameter_types! {
 	pub const TreasuryAccount: AccountId = TREASURY;
-	pub const One: AccountId = AccountId32::new([1u8; 32]);
+	pub const One: AccountId = ALICE;
 }
 impl Config for Runtime {
 	type Event = Event;
@@ -122,6 +127,8 @@ impl Config for Runtime {
 	type FundingOrigin = TreasuryAccount;
 	type DropOrigin = EnsureSignedBy<One, AccountId>;
 	type PalletId = AirdropPalletId;
+	type MaxMerkleProofLength = MaxMerkleProofLength;
+	type SweepTip = SweepTip;
 }
 
 pub type Block = sp_runtime::generic::Block<Header, UncheckedExtrinsic>;
@@ -134,19 +141,19 @@ construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic
 	{
 		System: frame_system::{Pallet, Storage, Call, Config, Event<T>},
-		AirDrop: airdrop::{Pallet, Storage, Call, Event<T>},
-		Tokens: orml_tokens::{Pallet, Storage, Call, Event<T>},
+		Airdrop: airdrop::{Pallet, Storage, Call, Event<T>},
+		Tokens: orml_tokens::{Pallet, Storage, Call, Event<T>, Config<T>},
 	}
 );
 
 pub struct ExtBuilder {
-	_balances: Vec<(AccountId, CurrencyId, Balance)>,
+	balances: Vec<(AccountId, CurrencyId, Balance)>,
 }
 
 impl Default for ExtBuilder {
 	fn default() -> Self {
 		Self {
-			_balances: vec![
+			balances: vec![
 				(ALICE, SETR, 1000),
 				(BOB, SETR, 1000),
 				(TREASURY, SETR, 1000),
@@ -166,3 +173,21 @@ impl Default for ExtBuilder {
 		}
 	}
 }
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap();
+
+		orml_tokens::GenesisConfig::<Runtime> {
+			balances: self.balances,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
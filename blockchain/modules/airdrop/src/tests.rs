@@ -24,7 +24,9 @@
 
 use super::*;
 use frame_support::{assert_noop, assert_ok};
-use mock::{Airdrop, Event, ExtBuilder, Origin, System, SETR, ALICE, BOB, CHARLIE, DAVE, EVE, TREASURY, SETUSD};
+use mock::{
+	Airdrop, Event, ExtBuilder, Origin, Runtime, System, Tokens, ALICE, BOB, CHARLIE, DAVE, EVE, SETR, SETUSD, TREASURY,
+};
 use sp_runtime::traits::BadOrigin;
 
 #[test]
@@ -40,36 +42,36 @@ fn fund_airdrop_treasury_works() {
         let airdrop_treasury = Airdrop::account_id();
 
         assert_ok!(Airdrop::fund_airdrop_treasury(Origin::signed(ALICE), SETR, 258));
-        System::assert_last_event(Event::AirDrop(
+        System::assert_last_event(Event::Airdrop(
             crate::Event::FundAirdropTreasury {
                 funder: ALICE,
                 currency_id: SETR,
                 amount: 258
             },
         ));
-        assert_eq!(Tokens::free_balance(SETUSD, airdrop_treasury), 0);
-        assert_eq!(Tokens::free_balance(SETR, airdrop_treasury), 258);
+        assert_eq!(Tokens::free_balance(SETUSD, &airdrop_treasury), 0);
+        assert_eq!(Tokens::free_balance(SETR, &airdrop_treasury), 258);
 
 
         assert_ok!(Airdrop::fund_airdrop_treasury(Origin::signed(ALICE), SETR, 10));
-        System::assert_last_event(Event::AirDrop(
+        System::assert_last_event(Event::Airdrop(
             crate::Event::FundAirdropTreasury {
                 funder: ALICE,
                 currency_id: SETR,
                 amount: 10
             },
         ));
-         assert_eq!(Tokens::free_balance(SETR, airdrop_treasury), 268);
+         assert_eq!(Tokens::free_balance(SETR, &airdrop_treasury), 268);
 
         assert_ok!(Airdrop::fund_airdrop_treasury(Origin::signed(ALICE), SETUSD, 258));
-        System::assert_last_event(Event::AirDrop(
+        System::assert_last_event(Event::Airdrop(
             crate::Event::FundAirdropTreasury {
                 funder: ALICE,
                 currency_id: SETUSD,
                 amount: 258
             },
         ));
-        assert_eq!(Tokens::free_balance(SETUSD, airdrop_treasury), 258);
+        assert_eq!(Tokens::free_balance(SETUSD, &airdrop_treasury), 258);
 	});
 }
 
@@ -81,52 +83,53 @@ fn make_airdrop_works() {
             (BOB, 5),
             (CHARLIE, 20),
         ];
+        let airdrop_treasury = Airdrop::account_id();
 
 		assert_noop!(Airdrop::make_airdrop(
             Origin::signed(BOB),
             SETUSD,
-            airdrop_list
+            airdrop_list.clone()
         ),
         BadOrigin
         );
 
         assert_ok!(Airdrop::fund_airdrop_treasury(Origin::signed(ALICE), SETR, 258));
-        System::assert_last_event(Event::AirDrop(
+        System::assert_last_event(Event::Airdrop(
             crate::Event::FundAirdropTreasury {
                 funder: ALICE,
                 currency_id: SETR,
                 amount: 258
             },
         ));
-        assert_eq!(Tokens::free_balance(SETR, airdrop_treasury), 258);
+        assert_eq!(Tokens::free_balance(SETR, &airdrop_treasury), 258);
 
         assert_ok!(Airdrop::fund_airdrop_treasury(Origin::signed(ALICE), SETUSD, 258));
-        System::assert_last_event(Event::AirDrop(
+        System::assert_last_event(Event::Airdrop(
             crate::Event::FundAirdropTreasury {
                 funder: ALICE,
                 currency_id: SETUSD,
                 amount: 258
             },
         ));
-        assert_eq!(Tokens::free_balance(SETUSD, airdrop_treasury), 258);
+        assert_eq!(Tokens::free_balance(SETUSD, &airdrop_treasury), 258);
 
         assert_ok!(Airdrop::make_airdrop(
             Origin::signed(ALICE),
             SETR,
-            airdrop_list
+            airdrop_list.clone()
         ));
-        System::assert_last_event(Event::AirDrop(
+        System::assert_last_event(Event::Airdrop(
             crate::Event::Airdrop {
                 currency_id: SETR,
-                airdrop_list
+                airdrop_list: airdrop_list.clone()
             },
         ));
         assert_ok!(Airdrop::make_airdrop(
             Origin::signed(ALICE),
             SETUSD,
-            airdrop_list
+            airdrop_list.clone()
         ));
-        System::assert_last_event(Event::AirDrop(
+        System::assert_last_event(Event::Airdrop(
             crate::Event::Airdrop {
                 currency_id: SETUSD,
                 airdrop_list
@@ -145,31 +148,238 @@ fn make_airdrop_does_not_work() {
             (DAVE, 20),
             (EVE, 20),
         ];
+        let airdrop_treasury = Airdrop::account_id();
 
 		assert_noop!(Airdrop::make_airdrop(
             Origin::signed(BOB),
             SETUSD,
-            airdrop_list
+            airdrop_list.clone()
         ),
         BadOrigin
         );
 
         assert_ok!(Airdrop::fund_airdrop_treasury(Origin::signed(ALICE), SETR, 258));
-        System::assert_last_event(Event::AirDrop(
+        System::assert_last_event(Event::Airdrop(
             crate::Event::FundAirdropTreasury {
                 funder: ALICE,
                 currency_id: SETR,
                 amount: 258
             },
         ));
-        assert_eq!(Tokens::free_balance(SETR, airdrop_treasury), 258);
+        assert_eq!(Tokens::free_balance(SETR, &airdrop_treasury), 258);
 
         assert_noop!(Airdrop::make_airdrop(
             Origin::signed(ALICE),
             SETR,
             airdrop_list
-        )
+        ),
         Error::<Runtime>::OverSizedAirdropList,
         );
 	});
 }
+
+#[test]
+fn merkle_drop_claim_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let leaf_alice = Airdrop::merkle_leaf(&ALICE, 10);
+		let leaf_bob = Airdrop::merkle_leaf(&BOB, 20);
+		let merkle_root = if leaf_alice <= leaf_bob {
+			H256::from(blake2_256(&[leaf_alice.as_bytes(), leaf_bob.as_bytes()].concat()))
+		} else {
+			H256::from(blake2_256(&[leaf_bob.as_bytes(), leaf_alice.as_bytes()].concat()))
+		};
+
+		assert_noop!(
+			Airdrop::create_merkle_drop(Origin::signed(BOB), SETR, merkle_root, 30, 100),
+			BadOrigin
+		);
+		assert_noop!(
+			Airdrop::create_merkle_drop(Origin::signed(ALICE), SETR, merkle_root, 30, 100),
+			Error::<Runtime>::BudgetExceeded
+		);
+
+		assert_ok!(Airdrop::fund_airdrop_treasury(Origin::signed(ALICE), SETR, 258));
+		assert_ok!(Airdrop::create_merkle_drop(Origin::signed(ALICE), SETR, merkle_root, 30, 100));
+		System::assert_last_event(Event::Airdrop(crate::Event::MerkleDropCreated {
+			drop_id: 0,
+			currency_id: SETR,
+			merkle_root,
+			total_allocation: 30,
+		}));
+		assert_eq!(Airdrop::next_merkle_drop_id(), 1);
+
+		assert_noop!(
+			Airdrop::claim(Origin::signed(ALICE), 1, 10, vec![leaf_bob]),
+			Error::<Runtime>::MerkleDropNotFound
+		);
+		assert_noop!(
+			Airdrop::claim(Origin::signed(ALICE), 0, 20, vec![leaf_bob]),
+			Error::<Runtime>::InvalidMerkleProof
+		);
+
+		let alice_balance = Tokens::free_balance(SETR, &ALICE);
+		assert_ok!(Airdrop::claim(Origin::signed(ALICE), 0, 10, vec![leaf_bob]));
+		System::assert_last_event(Event::Airdrop(crate::Event::Claimed {
+			drop_id: 0,
+			who: ALICE,
+			amount: 10,
+		}));
+		assert_eq!(Tokens::free_balance(SETR, &ALICE), alice_balance + 10);
+		assert_eq!(Airdrop::merkle_drops(0).unwrap().claimed, 10);
+		assert!(Airdrop::claimed(0, &ALICE));
+
+		assert_noop!(
+			Airdrop::claim(Origin::signed(ALICE), 0, 10, vec![leaf_bob]),
+			Error::<Runtime>::AlreadyClaimed
+		);
+
+		assert_ok!(Airdrop::claim(Origin::signed(BOB), 0, 20, vec![leaf_alice]));
+		assert_eq!(Airdrop::merkle_drops(0).unwrap().claimed, 30);
+		assert_eq!(Tokens::free_balance(SETR, &Airdrop::account_id()), 228);
+	});
+}
+
+#[test]
+fn drop_budget_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(Airdrop::drop_budget(SETR), 0);
+
+		assert_ok!(Airdrop::fund_airdrop(Origin::signed(BOB), SETR, 30));
+		System::assert_last_event(Event::Airdrop(crate::Event::FundAirdropTreasury {
+			funder: BOB,
+			currency_id: SETR,
+			amount: 30,
+		}));
+		assert_eq!(Airdrop::drop_budget(SETR), 30);
+		assert_eq!(Tokens::free_balance(SETR, &Airdrop::account_id()), 30);
+
+		assert_noop!(
+			Airdrop::make_airdrop(Origin::signed(ALICE), SETR, vec![(ALICE, 20), (BOB, 20)]),
+			Error::<Runtime>::BudgetExceeded
+		);
+		assert_noop!(
+			Airdrop::make_airdrop(Origin::signed(ALICE), SETUSD, vec![(ALICE, 1)]),
+			Error::<Runtime>::BudgetExceeded
+		);
+
+		assert_ok!(Airdrop::make_airdrop(Origin::signed(ALICE), SETR, vec![(ALICE, 10), (BOB, 15)]));
+		assert_eq!(Airdrop::drop_budget(SETR), 5);
+		assert_eq!(Tokens::free_balance(SETR, &Airdrop::account_id()), 5);
+
+		assert_noop!(
+			Airdrop::create_merkle_drop(Origin::signed(ALICE), SETR, H256::zero(), 6, 100),
+			Error::<Runtime>::BudgetExceeded
+		);
+		assert_noop!(
+			Airdrop::create_merkle_drop(Origin::signed(ALICE), SETR, H256::zero(), 5, 1),
+			Error::<Runtime>::InvalidExpiry
+		);
+		assert_ok!(Airdrop::create_merkle_drop(Origin::signed(ALICE), SETR, H256::zero(), 5, 100));
+		assert_eq!(Airdrop::drop_budget(SETR), 0);
+	});
+}
+
+#[test]
+fn make_airdrop_batch_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			Airdrop::make_airdrop_batch(Origin::signed(BOB), SETR, vec![(ALICE, 10)]),
+			BadOrigin
+		);
+		assert_noop!(
+			Airdrop::make_airdrop_batch(
+				Origin::signed(ALICE),
+				SETR,
+				vec![(ALICE, 1), (BOB, 1), (CHARLIE, 1), (DAVE, 1), (EVE, 1)]
+			),
+			Error::<Runtime>::OverSizedAirdropList
+		);
+
+		assert_ok!(Airdrop::fund_airdrop(Origin::signed(BOB), SETR, 30));
+		assert_ok!(Airdrop::make_airdrop_batch(
+			Origin::signed(ALICE),
+			SETR,
+			vec![(ALICE, 10), (ALICE, 10), (BOB, 25), (CHARLIE, 15)]
+		));
+		System::assert_has_event(Event::Airdrop(crate::Event::AirdropBatchItem {
+			currency_id: SETR,
+			who: ALICE,
+			amount: 10,
+		}));
+		System::assert_has_event(Event::Airdrop(crate::Event::AirdropBatchItemFailed {
+			currency_id: SETR,
+			who: ALICE,
+			amount: 10,
+			error: Error::<Runtime>::DuplicateAccounts.into(),
+		}));
+		System::assert_has_event(Event::Airdrop(crate::Event::AirdropBatchItemFailed {
+			currency_id: SETR,
+			who: BOB,
+			amount: 25,
+			error: Error::<Runtime>::BudgetExceeded.into(),
+		}));
+		System::assert_has_event(Event::Airdrop(crate::Event::AirdropBatchItem {
+			currency_id: SETR,
+			who: CHARLIE,
+			amount: 15,
+		}));
+		System::assert_last_event(Event::Airdrop(crate::Event::AirdropBatch {
+			currency_id: SETR,
+			succeeded: 2,
+			failed: 2,
+		}));
+		assert_eq!(Airdrop::drop_budget(SETR), 5);
+		assert_eq!(Tokens::free_balance(SETR, &Airdrop::account_id()), 5);
+		assert_eq!(Tokens::free_balance(SETR, &CHARLIE), 15);
+	});
+}
+
+#[test]
+fn sweep_expired_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		let leaf_alice = Airdrop::merkle_leaf(&ALICE, 10);
+		let leaf_bob = Airdrop::merkle_leaf(&BOB, 90);
+		let merkle_root = if leaf_alice <= leaf_bob {
+			H256::from(blake2_256(&[leaf_alice.as_bytes(), leaf_bob.as_bytes()].concat()))
+		} else {
+			H256::from(blake2_256(&[leaf_bob.as_bytes(), leaf_alice.as_bytes()].concat()))
+		};
+
+		assert_ok!(Airdrop::fund_airdrop(Origin::signed(BOB), SETR, 100));
+		assert_ok!(Airdrop::create_merkle_drop(Origin::signed(ALICE), SETR, merkle_root, 100, 10));
+		assert_ok!(Airdrop::claim(Origin::signed(ALICE), 0, 10, vec![leaf_bob]));
+
+		assert_noop!(
+			Airdrop::sweep_expired(Origin::signed(CHARLIE), 0),
+			Error::<Runtime>::MerkleDropNotExpired
+		);
+
+		System::set_block_number(10);
+		assert_noop!(
+			Airdrop::claim(Origin::signed(BOB), 0, 90, vec![leaf_alice]),
+			Error::<Runtime>::MerkleDropExpired
+		);
+
+		let treasury_balance = Tokens::free_balance(SETR, &TREASURY);
+		assert_ok!(Airdrop::sweep_expired(Origin::signed(CHARLIE), 0));
+		System::assert_last_event(Event::Airdrop(crate::Event::MerkleDropSwept {
+			drop_id: 0,
+			who: CHARLIE,
+			unclaimed: 90,
+			tip: 9,
+		}));
+		assert_eq!(Tokens::free_balance(SETR, &CHARLIE), 9);
+		assert_eq!(Tokens::free_balance(SETR, &TREASURY), treasury_balance + 81);
+		assert_eq!(Airdrop::merkle_drops(0), None);
+		assert!(Airdrop::claimed(0, &ALICE));
+
+		assert_noop!(
+			Airdrop::sweep_expired(Origin::signed(CHARLIE), 0),
+			Error::<Runtime>::MerkleDropNotFound
+		);
+	});
+}
@@ -0,0 +1,187 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Asset Registry Module
+//!
+//! ## Overview
+//!
+//! Foreign and bridged assets are registered here at runtime by governance
+//! instead of being hardcoded in `create_currency_id!`. Each registered asset
+//! is assigned the next `ForeignAssetId` and is used as
+//! `CurrencyId::ForeignAsset(ForeignAssetId)`, with its name, symbol, decimals
+//! and existential deposit kept in `AssetMetadatas`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, transactional};
+use frame_system::pallet_prelude::*;
+use primitives::{AssetMetadata, Balance, CurrencyId, ForeignAssetId};
+use sp_runtime::ArithmeticError;
+use sp_std::{boxed::Box, vec::Vec};
+use support::ForeignAssetIdMapping;
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The origin which may register and update foreign assets.
+		type RegisterOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The maximum length of the name or symbol of an asset.
+		#[pallet::constant]
+		type MaxMetadataLength: Get<u32>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The foreign asset does not exist.
+		AssetIdNotExists,
+		/// The name or symbol is empty or longer than `MaxMetadataLength`.
+		BadMetadata,
+		/// The decimals or the minimal balance of a registered asset can't be
+		/// changed.
+		ImmutableMetadata,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A foreign asset was registered. \[foreign_asset_id, metadata\]
+		ForeignAssetRegistered(ForeignAssetId, AssetMetadata<Balance>),
+		/// The metadata of a foreign asset was updated. \[foreign_asset_id, metadata\]
+		ForeignAssetUpdated(ForeignAssetId, AssetMetadata<Balance>),
+	}
+
+	/// The next ForeignAssetId to be assigned.
+	///
+	/// NextForeignAssetId: ForeignAssetId
+	#[pallet::storage]
+	#[pallet::getter(fn next_foreign_asset_id)]
+	pub type NextForeignAssetId<T: Config> = StorageValue<_, ForeignAssetId, ValueQuery>;
+
+	/// The metadata of the registered foreign assets.
+	///
+	/// AssetMetadatas: map ForeignAssetId => Option<AssetMetadata>
+	#[pallet::storage]
+	#[pallet::getter(fn asset_metadatas)]
+	pub type AssetMetadatas<T: Config> =
+		StorageMap<_, Twox64Concat, ForeignAssetId, AssetMetadata<Balance>, OptionQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register a foreign asset under the next `ForeignAssetId`.
+		///
+		/// The dispatch origin of this call must be `RegisterOrigin`.
+		#[pallet::weight(T::WeightInfo::register_foreign_asset())]
+		#[transactional]
+		pub fn register_foreign_asset(origin: OriginFor<T>, metadata: Box<AssetMetadata<Balance>>) -> DispatchResult {
+			T::RegisterOrigin::ensure_origin(origin)?;
+			Self::ensure_valid_metadata(&metadata)?;
+
+			let foreign_asset_id = NextForeignAssetId::<T>::try_mutate(|current| -> Result<ForeignAssetId, DispatchError> {
+				let id = *current;
+				*current = current.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+				Ok(id)
+			})?;
+			AssetMetadatas::<T>::insert(foreign_asset_id, &*metadata);
+
+			Self::deposit_event(Event::ForeignAssetRegistered(foreign_asset_id, *metadata));
+			Ok(())
+		}
+
+		/// Update the name and symbol of a registered foreign asset, the
+		/// `decimals` and `minimal_balance` must stay the same as the
+		/// balances already held are denominated in them.
+		///
+		/// The dispatch origin of this call must be `RegisterOrigin`.
+		#[pallet::weight(T::WeightInfo::update_foreign_asset())]
+		#[transactional]
+		pub fn update_foreign_asset(
+			origin: OriginFor<T>,
+			foreign_asset_id: ForeignAssetId,
+			metadata: Box<AssetMetadata<Balance>>,
+		) -> DispatchResult {
+			T::RegisterOrigin::ensure_origin(origin)?;
+			Self::ensure_valid_metadata(&metadata)?;
+
+			AssetMetadatas::<T>::try_mutate(foreign_asset_id, |maybe_metadata| -> DispatchResult {
+				let current = maybe_metadata.as_mut().ok_or(Error::<T>::AssetIdNotExists)?;
+				ensure!(
+					current.decimals == metadata.decimals && current.minimal_balance == metadata.minimal_balance,
+					Error::<T>::ImmutableMetadata
+				);
+				*current = (*metadata).clone();
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ForeignAssetUpdated(foreign_asset_id, *metadata));
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn ensure_valid_metadata(metadata: &AssetMetadata<Balance>) -> DispatchResult {
+		let is_valid = |v: &Vec<u8>| !v.is_empty() && v.len() <= T::MaxMetadataLength::get() as usize;
+		ensure!(
+			is_valid(&metadata.name) && is_valid(&metadata.symbol),
+			Error::<T>::BadMetadata
+		);
+		Ok(())
+	}
+
+	/// The existential deposit of a registered foreign asset, `None` if the
+	/// `currency_id` is not a registered foreign asset.
+	pub fn existential_deposit(currency_id: CurrencyId) -> Option<Balance> {
+		match currency_id {
+			CurrencyId::ForeignAsset(foreign_asset_id) => Self::asset_metadatas(foreign_asset_id).map(|v| v.minimal_balance),
+			_ => None,
+		}
+	}
+}
+
+pub struct AssetIdMaps<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> ForeignAssetIdMapping<ForeignAssetId, AssetMetadata<Balance>> for AssetIdMaps<T> {
+	fn get_asset_metadata(foreign_asset_id: ForeignAssetId) -> Option<AssetMetadata<Balance>> {
+		Pallet::<T>::asset_metadatas(foreign_asset_id)
+	}
+}
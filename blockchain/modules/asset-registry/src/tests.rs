@@ -0,0 +1,164 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the asset registry module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Event, *};
+use sp_runtime::traits::BadOrigin;
+
+fn foreign_token() -> AssetMetadata<Balance> {
+	AssetMetadata {
+		name: b"Foreign Token".to_vec(),
+		symbol: b"FT".to_vec(),
+		decimals: 12,
+		minimal_balance: 1_000,
+	}
+}
+
+#[test]
+fn register_foreign_asset_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			AssetRegistry::register_foreign_asset(Origin::signed(5), Box::new(foreign_token())),
+			BadOrigin
+		);
+
+		assert_eq!(AssetRegistry::next_foreign_asset_id(), 0);
+		assert_ok!(AssetRegistry::register_foreign_asset(
+			Origin::signed(ALICE),
+			Box::new(foreign_token())
+		));
+		System::assert_last_event(Event::AssetRegistry(crate::Event::ForeignAssetRegistered(
+			0,
+			foreign_token(),
+		)));
+		assert_eq!(AssetRegistry::asset_metadatas(0), Some(foreign_token()));
+		assert_eq!(AssetRegistry::next_foreign_asset_id(), 1);
+		assert_eq!(
+			AssetIdMaps::<Runtime>::get_asset_metadata(0),
+			Some(foreign_token())
+		);
+		assert_eq!(
+			AssetRegistry::existential_deposit(CurrencyId::ForeignAsset(0)),
+			Some(1_000)
+		);
+		assert_eq!(AssetRegistry::existential_deposit(CurrencyId::ForeignAsset(1)), None);
+
+		assert_ok!(AssetRegistry::register_foreign_asset(
+			Origin::signed(ALICE),
+			Box::new(foreign_token())
+		));
+		assert_eq!(AssetRegistry::next_foreign_asset_id(), 2);
+	});
+}
+
+#[test]
+fn register_foreign_asset_reject_bad_metadata() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut metadata = foreign_token();
+		metadata.symbol = vec![];
+		assert_noop!(
+			AssetRegistry::register_foreign_asset(Origin::signed(ALICE), Box::new(metadata)),
+			Error::<Runtime>::BadMetadata
+		);
+
+		let mut metadata = foreign_token();
+		metadata.name = vec![b'a'; 33];
+		assert_noop!(
+			AssetRegistry::register_foreign_asset(Origin::signed(ALICE), Box::new(metadata)),
+			Error::<Runtime>::BadMetadata
+		);
+	});
+}
+
+#[test]
+fn register_foreign_asset_overflow() {
+	ExtBuilder::default().build().execute_with(|| {
+		NextForeignAssetId::<Runtime>::put(ForeignAssetId::MAX);
+		assert_noop!(
+			AssetRegistry::register_foreign_asset(Origin::signed(ALICE), Box::new(foreign_token())),
+			ArithmeticError::Overflow
+		);
+	});
+}
+
+#[test]
+fn update_foreign_asset_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		let mut metadata = foreign_token();
+		metadata.name = b"Renamed Foreign Token".to_vec();
+		assert_noop!(
+			AssetRegistry::update_foreign_asset(Origin::signed(ALICE), 0, Box::new(metadata.clone())),
+			Error::<Runtime>::AssetIdNotExists
+		);
+
+		assert_ok!(AssetRegistry::register_foreign_asset(
+			Origin::signed(ALICE),
+			Box::new(foreign_token())
+		));
+		assert_noop!(
+			AssetRegistry::update_foreign_asset(Origin::signed(5), 0, Box::new(metadata.clone())),
+			BadOrigin
+		);
+		assert_ok!(AssetRegistry::update_foreign_asset(
+			Origin::signed(ALICE),
+			0,
+			Box::new(metadata.clone())
+		));
+		System::assert_last_event(Event::AssetRegistry(crate::Event::ForeignAssetUpdated(
+			0,
+			metadata.clone(),
+		)));
+		assert_eq!(AssetRegistry::asset_metadatas(0), Some(metadata));
+		assert_eq!(AssetRegistry::next_foreign_asset_id(), 1);
+	});
+}
+
+#[test]
+fn update_foreign_asset_keeps_decimals_and_minimal_balance() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AssetRegistry::register_foreign_asset(
+			Origin::signed(ALICE),
+			Box::new(foreign_token())
+		));
+
+		let mut metadata = foreign_token();
+		metadata.minimal_balance = 10;
+		assert_noop!(
+			AssetRegistry::update_foreign_asset(Origin::signed(ALICE), 0, Box::new(metadata)),
+			Error::<Runtime>::ImmutableMetadata
+		);
+
+		let mut metadata = foreign_token();
+		metadata.decimals = 18;
+		assert_noop!(
+			AssetRegistry::update_foreign_asset(Origin::signed(ALICE), 0, Box::new(metadata)),
+			Error::<Runtime>::ImmutableMetadata
+		);
+	});
+}
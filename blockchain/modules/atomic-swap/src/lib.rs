@@ -0,0 +1,260 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Atomic Swap Module
+//!
+//! ## Overview
+//!
+//! Hashed-timelock contracts for OTC and cross-chain swaps of any
+//! `CurrencyId`. The source reserves `amount` for the `target` behind the
+//! SHA-256 hash of a secret, which is the hash Bitcoin HTLC scripts use.
+//! The target claims the funds by revealing the secret before the swap
+//! expires; after that the source can refund them.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use codec::{Decode, Encode};
+use frame_support::{pallet_prelude::*, transactional};
+use frame_system::pallet_prelude::*;
+use orml_traits::{BalanceStatus, MultiReservableCurrency};
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	RuntimeDebug,
+};
+use sp_std::vec::Vec;
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+/// The SHA-256 hash of a swap secret.
+pub type HashedProof = [u8; 32];
+
+/// A swap waiting to be claimed by its target or refunded to its source.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct PendingSwap<AccountId, BlockNumber> {
+	/// The account that can claim the swap.
+	pub target: AccountId,
+	/// The currency reserved from the source.
+	pub currency_id: CurrencyId,
+	/// The amount reserved from the source.
+	pub amount: Balance,
+	/// The block from which the swap can no longer be claimed and can be
+	/// refunded.
+	pub end_block: BlockNumber,
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Currency to reserve the swapped funds in.
+		type MultiCurrency: MultiReservableCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// The maximum number of blocks a swap can stay claimable.
+		#[pallet::constant]
+		type MaxSwapDuration: Get<Self::BlockNumber>;
+
+		/// The maximum length of a swap secret.
+		#[pallet::constant]
+		type ProofLimit: Get<u32>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The source already has a swap with this hashed proof.
+		SwapAlreadyExists,
+		/// The swap does not exist.
+		SwapNotExists,
+		/// The amount is zero.
+		ZeroAmount,
+		/// The duration is zero or longer than `MaxSwapDuration`.
+		InvalidDuration,
+		/// The secret is longer than `ProofLimit`.
+		ProofTooLarge,
+		/// The caller is not the target of the swap.
+		NotTarget,
+		/// The swap has expired and can no longer be claimed.
+		SwapExpired,
+		/// The swap has not expired yet and cannot be refunded.
+		SwapNotExpired,
+		/// The reserved balance of the source no longer covers the swap.
+		ReservedBalanceTooLow,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A swap was created. \[source, target, hashed_proof, currency_id,
+		/// amount, end_block\]
+		SwapCreated(T::AccountId, T::AccountId, HashedProof, CurrencyId, Balance, T::BlockNumber),
+		/// A swap was claimed by its target, revealing the secret. \[source,
+		/// target, hashed_proof, secret\]
+		SwapClaimed(T::AccountId, T::AccountId, HashedProof, Vec<u8>),
+		/// An expired swap was refunded to its source. \[source,
+		/// hashed_proof\]
+		SwapRefunded(T::AccountId, HashedProof),
+	}
+
+	/// The pending swaps, keyed by source and hashed proof.
+	///
+	/// PendingSwaps: double_map AccountId, HashedProof => Option<PendingSwap>
+	#[pallet::storage]
+	#[pallet::getter(fn pending_swaps)]
+	pub type PendingSwaps<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		HashedProof,
+		PendingSwap<T::AccountId, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Reserve `amount` of `currency_id` for `target`, claimable by
+		/// revealing the secret behind `hashed_proof` within `duration`
+		/// blocks.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		#[pallet::weight(T::WeightInfo::create_swap())]
+		#[transactional]
+		pub fn create_swap(
+			origin: OriginFor<T>,
+			hashed_proof: HashedProof,
+			target: T::AccountId,
+			currency_id: CurrencyId,
+			amount: Balance,
+			duration: T::BlockNumber,
+		) -> DispatchResult {
+			let source = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			ensure!(
+				!duration.is_zero() && duration <= T::MaxSwapDuration::get(),
+				Error::<T>::InvalidDuration
+			);
+			ensure!(
+				!PendingSwaps::<T>::contains_key(&source, hashed_proof),
+				Error::<T>::SwapAlreadyExists
+			);
+
+			T::MultiCurrency::reserve(currency_id, &source, amount)?;
+
+			let end_block = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+			PendingSwaps::<T>::insert(
+				&source,
+				hashed_proof,
+				PendingSwap {
+					target: target.clone(),
+					currency_id,
+					amount,
+					end_block,
+				},
+			);
+
+			Self::deposit_event(Event::SwapCreated(
+				source,
+				target,
+				hashed_proof,
+				currency_id,
+				amount,
+				end_block,
+			));
+			Ok(())
+		}
+
+		/// Claim the swap of `source` by revealing its `secret`.
+		///
+		/// The dispatch origin of this call must be _Signed_ by the target of
+		/// the swap.
+		#[pallet::weight(T::WeightInfo::claim(secret.len() as u32))]
+		#[transactional]
+		pub fn claim(origin: OriginFor<T>, source: T::AccountId, secret: Vec<u8>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				secret.len() <= T::ProofLimit::get() as usize,
+				Error::<T>::ProofTooLarge
+			);
+
+			let hashed_proof = sp_io::hashing::sha2_256(&secret);
+			let swap = Self::pending_swaps(&source, hashed_proof).ok_or(Error::<T>::SwapNotExists)?;
+			ensure!(who == swap.target, Error::<T>::NotTarget);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() < swap.end_block,
+				Error::<T>::SwapExpired
+			);
+
+			let remaining = T::MultiCurrency::repatriate_reserved(
+				swap.currency_id,
+				&source,
+				&swap.target,
+				swap.amount,
+				BalanceStatus::Free,
+			)?;
+			// the target must get the full amount or nothing
+			ensure!(remaining.is_zero(), Error::<T>::ReservedBalanceTooLow);
+			PendingSwaps::<T>::remove(&source, hashed_proof);
+
+			Self::deposit_event(Event::SwapClaimed(source, swap.target, hashed_proof, secret));
+			Ok(())
+		}
+
+		/// Refund an expired swap back to its source.
+		///
+		/// The dispatch origin of this call must be _Signed_ by the source of
+		/// the swap.
+		#[pallet::weight(T::WeightInfo::refund())]
+		#[transactional]
+		pub fn refund(origin: OriginFor<T>, hashed_proof: HashedProof) -> DispatchResult {
+			let source = ensure_signed(origin)?;
+
+			let swap = Self::pending_swaps(&source, hashed_proof).ok_or(Error::<T>::SwapNotExists)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= swap.end_block,
+				Error::<T>::SwapNotExpired
+			);
+
+			T::MultiCurrency::unreserve(swap.currency_id, &source, swap.amount);
+			PendingSwaps::<T>::remove(&source, hashed_proof);
+
+			Self::deposit_event(Event::SwapRefunded(source, hashed_proof));
+			Ok(())
+		}
+	}
+}
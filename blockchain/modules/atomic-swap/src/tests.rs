@@ -0,0 +1,179 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the atomic swap module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{AtomicSwap, Event, ExtBuilder, Origin, Runtime, System, Tokens, ALICE, BOB, CHARLIE, SETR, USSD};
+use orml_traits::MultiCurrency;
+
+const SECRET: &[u8] = b"setheum atomic swap secret";
+
+fn hashed_proof() -> HashedProof {
+	sp_io::hashing::sha2_256(SECRET)
+}
+
+#[test]
+fn create_swap_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AtomicSwap::create_swap(
+			Origin::signed(ALICE),
+			hashed_proof(),
+			BOB,
+			USSD,
+			100,
+			10
+		));
+		System::assert_last_event(Event::AtomicSwap(crate::Event::SwapCreated(
+			ALICE,
+			BOB,
+			hashed_proof(),
+			USSD,
+			100,
+			11,
+		)));
+		assert_eq!(
+			AtomicSwap::pending_swaps(ALICE, hashed_proof()),
+			Some(PendingSwap {
+				target: BOB,
+				currency_id: USSD,
+				amount: 100,
+				end_block: 11,
+			})
+		);
+		assert_eq!(Tokens::free_balance(USSD, &ALICE), 900);
+		assert_eq!(Tokens::reserved_balance(USSD, &ALICE), 100);
+	});
+}
+
+#[test]
+fn create_swap_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AtomicSwap::create_swap(Origin::signed(ALICE), hashed_proof(), BOB, USSD, 0, 10),
+			Error::<Runtime>::ZeroAmount
+		);
+		assert_noop!(
+			AtomicSwap::create_swap(Origin::signed(ALICE), hashed_proof(), BOB, USSD, 100, 0),
+			Error::<Runtime>::InvalidDuration
+		);
+		assert_noop!(
+			AtomicSwap::create_swap(Origin::signed(ALICE), hashed_proof(), BOB, USSD, 100, 101),
+			Error::<Runtime>::InvalidDuration
+		);
+		assert_noop!(
+			AtomicSwap::create_swap(Origin::signed(BOB), hashed_proof(), ALICE, USSD, 100, 10),
+			orml_tokens::Error::<Runtime>::BalanceTooLow
+		);
+
+		assert_ok!(AtomicSwap::create_swap(Origin::signed(ALICE), hashed_proof(), BOB, USSD, 100, 10));
+		assert_noop!(
+			AtomicSwap::create_swap(Origin::signed(ALICE), hashed_proof(), BOB, SETR, 100, 10),
+			Error::<Runtime>::SwapAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn claim_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AtomicSwap::create_swap(Origin::signed(ALICE), hashed_proof(), BOB, USSD, 100, 10));
+
+		assert_ok!(AtomicSwap::claim(Origin::signed(BOB), ALICE, SECRET.to_vec()));
+		System::assert_last_event(Event::AtomicSwap(crate::Event::SwapClaimed(
+			ALICE,
+			BOB,
+			hashed_proof(),
+			SECRET.to_vec(),
+		)));
+		assert_eq!(AtomicSwap::pending_swaps(ALICE, hashed_proof()), None);
+		assert_eq!(Tokens::free_balance(USSD, &ALICE), 900);
+		assert_eq!(Tokens::reserved_balance(USSD, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(USSD, &BOB), 100);
+	});
+}
+
+#[test]
+fn claim_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AtomicSwap::create_swap(Origin::signed(ALICE), hashed_proof(), BOB, USSD, 100, 10));
+
+		assert_noop!(
+			AtomicSwap::claim(Origin::signed(BOB), ALICE, b"wrong secret".to_vec()),
+			Error::<Runtime>::SwapNotExists
+		);
+		assert_noop!(
+			AtomicSwap::claim(Origin::signed(BOB), ALICE, vec![0u8; 65]),
+			Error::<Runtime>::ProofTooLarge
+		);
+		assert_noop!(
+			AtomicSwap::claim(Origin::signed(CHARLIE), ALICE, SECRET.to_vec()),
+			Error::<Runtime>::NotTarget
+		);
+
+		System::set_block_number(11);
+		assert_noop!(
+			AtomicSwap::claim(Origin::signed(BOB), ALICE, SECRET.to_vec()),
+			Error::<Runtime>::SwapExpired
+		);
+	});
+}
+
+#[test]
+fn claim_fails_if_reserved_balance_is_too_low() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AtomicSwap::create_swap(Origin::signed(ALICE), hashed_proof(), BOB, USSD, 100, 10));
+		// some of the reserved balance is released elsewhere
+		assert_eq!(Tokens::unreserve(USSD, &ALICE, 50), 0);
+
+		assert_noop!(
+			AtomicSwap::claim(Origin::signed(BOB), ALICE, SECRET.to_vec()),
+			Error::<Runtime>::ReservedBalanceTooLow
+		);
+		assert_eq!(Tokens::reserved_balance(USSD, &ALICE), 50);
+		assert_eq!(Tokens::free_balance(USSD, &BOB), 0);
+	});
+}
+
+#[test]
+fn refund_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AtomicSwap::create_swap(Origin::signed(ALICE), hashed_proof(), BOB, USSD, 100, 10));
+
+		assert_noop!(
+			AtomicSwap::refund(Origin::signed(BOB), hashed_proof()),
+			Error::<Runtime>::SwapNotExists
+		);
+		assert_noop!(
+			AtomicSwap::refund(Origin::signed(ALICE), hashed_proof()),
+			Error::<Runtime>::SwapNotExpired
+		);
+
+		System::set_block_number(11);
+		assert_ok!(AtomicSwap::refund(Origin::signed(ALICE), hashed_proof()));
+		System::assert_last_event(Event::AtomicSwap(crate::Event::SwapRefunded(ALICE, hashed_proof())));
+		assert_eq!(AtomicSwap::pending_swaps(ALICE, hashed_proof()), None);
+		assert_eq!(Tokens::free_balance(USSD, &ALICE), 1000);
+		assert_eq!(Tokens::reserved_balance(USSD, &ALICE), 0);
+	});
+}
@@ -0,0 +1,69 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::all)]
+
+use codec::{Codec, Decode, Encode};
+use primitives::{evm::EvmAddress, CurrencyId};
+use sp_runtime::RuntimeDebug;
+use sp_std::prelude::*;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// The metadata of a currency known to the chain.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct TokenMetadata {
+	pub currency_id: CurrencyId,
+	pub name: Vec<u8>,
+	pub symbol: Vec<u8>,
+	pub decimals: u8,
+	/// The address of the ERC20 mirror of this currency, if any.
+	pub evm_address: Option<EvmAddress>,
+	/// Whether this is one of the SERP stablecoins.
+	pub is_stable: bool,
+	/// Whether this is a DEX LP share.
+	pub is_dex_share: bool,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait CurrenciesApi<AccountId, Balance> where
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// Get the free balances of `who` in one call: the native currency,
+		/// every orml token and LP share the account holds, and a page of the
+		/// registered ERC20 mirrors. Zero balances are left out.
+		///
+		/// Each ERC20 mirror takes an EVM call, so only the mirrors from the
+		/// `erc20_offset`-th one are queried, at most `erc20_limit` of them
+		/// and never more than the runtime's own page size.
+		///
+		/// CDP and Settmint positions are not part of the portfolio, only the
+		/// free balances of the account are.
+		fn get_account_portfolio(who: AccountId, erc20_offset: u32, erc20_limit: u32) -> Vec<(CurrencyId, Balance)>;
+
+		/// Get the metadata of every token, registered ERC20 and foreign
+		/// asset, so clients don't need to hardcode the currency table.
+		fn get_token_list() -> Vec<TokenMetadata>;
+	}
+}
@@ -97,6 +97,9 @@ pub mod module {
 		/// The AccountId that can perform a sweep dust.
 		type SweepOrigin: EnsureOrigin<Self::Origin>;
 
+		/// The origin which may update the dust removal whitelist.
+		type DustRemovalWhitelistOrigin: EnsureOrigin<Self::Origin>;
+
 		/// Handler to burn or transfer account's dust
 		type OnDust: OnDust<Self::AccountId, CurrencyId, BalanceOf<Self>>;
 	}
@@ -129,8 +132,18 @@ pub mod module {
 		Withdrawn(CurrencyIdOf<T>, T::AccountId, BalanceOf<T>),
 		/// Dust swept. \[currency_id, who, amount\]
 		DustSwept(CurrencyIdOf<T>, T::AccountId, BalanceOf<T>),
+		/// An account was added to or removed from the dust removal whitelist.
+		/// \[who, whitelisted\]
+		DustRemovalWhitelistUpdated(T::AccountId, bool),
 	}
 
+	/// The accounts added by governance that are never reaped by dust
+	/// removal, on top of the module accounts the runtime exempts.
+	///
+	/// DustRemovalWhitelist: map AccountId => Option<()>
+	#[pallet::storage]
+	pub type DustRemovalWhitelist<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, (), OptionQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -218,6 +231,29 @@ pub mod module {
 			}
 			Ok(())
 		}
+
+		/// Add `who` to or remove it from the dust removal whitelist.
+		///
+		/// The dispatch origin of this call must be `DustRemovalWhitelistOrigin`.
+		#[pallet::weight(T::WeightInfo::set_dust_removal_whitelist())]
+		pub fn set_dust_removal_whitelist(origin: OriginFor<T>, who: T::AccountId, whitelisted: bool) -> DispatchResult {
+			T::DustRemovalWhitelistOrigin::ensure_origin(origin)?;
+			if whitelisted {
+				DustRemovalWhitelist::<T>::insert(&who, ());
+			} else {
+				DustRemovalWhitelist::<T>::remove(&who);
+			}
+
+			Self::deposit_event(Event::DustRemovalWhitelistUpdated(who, whitelisted));
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Whether governance has exempted `who` from dust removal.
+	pub fn is_dust_removal_whitelisted(who: &T::AccountId) -> bool {
+		DustRemovalWhitelist::<T>::contains_key(who)
 	}
 }
 
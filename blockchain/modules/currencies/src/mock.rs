@@ -36,7 +36,7 @@ use support::{
 	mocks::MockAddressMapping,
 	AddressMapping, DEXManager,
 	Ratio, Price, PriceProvider,
-	SwapLimit
+	SwapLimit, SwapPath
 };
 
 use super::*;
@@ -205,7 +205,7 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 		unimplemented!()
 	}
 
-	fn get_swap_amount(_: &[CurrencyId], _: SwapLimit<Balance>) -> Option<(Balance, Balance)> {
+	fn get_swap_amount(_: &SwapPath<CurrencyId>, _: SwapLimit<Balance>) -> Option<(Balance, Balance)> {
 		unimplemented!()
 	}
 
@@ -214,13 +214,13 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 		_: CurrencyId,
 		_: SwapLimit<Balance>,
 		_: Vec<Vec<CurrencyId>>,
-	) -> Option<Vec<CurrencyId>> {
+	) -> Option<SwapPath<CurrencyId>> {
 		unimplemented!()
 	}
 
 	fn swap_with_specific_path(
 		_: &AccountId,
-		_: &[CurrencyId],
+		_: &SwapPath<CurrencyId>,
 		_: SwapLimit<Balance>,
 	) -> sp_std::result::Result<(Balance, Balance), DispatchError> {
 		unimplemented!()
@@ -228,7 +228,7 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 
 	fn buyback_swap_with_specific_path(
 		_: &AccountId,
-		_: &[CurrencyId],
+		_: &SwapPath<CurrencyId>,
 		_: SwapLimit<Balance>,
 	) -> sp_std::result::Result<(Balance, Balance), DispatchError> {
 		unimplemented!()
@@ -236,7 +236,7 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 
 	fn swap_with_exact_target(
 		_who: &AccountId,
-		_path: &[CurrencyId],
+		_path: &SwapPath<CurrencyId>,
 		_exact_target_amount: Balance,
 		_max_supply_amount: Balance,
 	) -> DispatchResult {
@@ -328,6 +328,7 @@ impl Config for Runtime {
 	type AddressMapping = MockAddressMapping;
 	type EVMBridge = EVMBridge;
 	type SweepOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
+	type DustRemovalWhitelistOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
 	type OnDust = crate::TransferDust<Runtime, DustAccount>;
 }
 
@@ -348,7 +349,7 @@ frame_support::construct_runtime!(
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Tokens: tokens::{Pallet, Storage, Event<T>, Config<T>},
-		Currencies: currencies::{Pallet, Call, Event<T>},
+		Currencies: currencies::{Pallet, Call, Storage, Event<T>},
 		EVM: module_evm::{Pallet, Config<T>, Call, Storage, Event<T>},
 		EVMBridge: module_evm_bridge::{Pallet},
 	}
@@ -970,3 +970,36 @@ fn sweep_dust_erc20_not_allowed() {
 		);
 	});
 }
+
+#[test]
+fn set_dust_removal_whitelist_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Currencies::set_dust_removal_whitelist(Origin::signed(bob()), eva(), true),
+			DispatchError::BadOrigin
+		);
+
+		assert!(!Currencies::is_dust_removal_whitelisted(&eva()));
+		assert_ok!(Currencies::set_dust_removal_whitelist(
+			Origin::signed(CouncilAccount::get()),
+			eva(),
+			true
+		));
+		System::assert_last_event(Event::Currencies(crate::Event::DustRemovalWhitelistUpdated(
+			eva(),
+			true,
+		)));
+		assert!(Currencies::is_dust_removal_whitelisted(&eva()));
+
+		assert_ok!(Currencies::set_dust_removal_whitelist(
+			Origin::signed(CouncilAccount::get()),
+			eva(),
+			false
+		));
+		System::assert_last_event(Event::Currencies(crate::Event::DustRemovalWhitelistUpdated(
+			eva(),
+			false,
+		)));
+		assert!(!Currencies::is_dust_removal_whitelisted(&eva()));
+	});
+}
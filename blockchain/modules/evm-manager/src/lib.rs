@@ -24,12 +24,15 @@
 //!
 //! Evm manager module provides common support features for Evm, including:
 //! - A two way mapping between `u32` and `Erc20 address` so user can use Erc20 address as LP token.
+//! - A cache of the name, symbol and decimals of the mapped Erc20 contracts, refreshable by anyone
+//!   who pays for the calls into the contract.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
-use frame_support::{ensure, pallet_prelude::*, require_transactional, traits::Currency};
-use module_support::{CurrencyIdMapping, EVMBridge, InvokeContext};
+use frame_support::{ensure, pallet_prelude::*, require_transactional, traits::Currency, transactional};
+use frame_system::pallet_prelude::*;
+use module_support::{CurrencyIdMapping, EVMBridge, ForeignAssetIdMapping, InvokeContext};
 use primitives::{
 	currency::TokenInfo,
 	evm::{Erc20Info, EvmAddress},
@@ -42,8 +45,10 @@ use sp_std::{
 
 mod mock;
 mod tests;
+pub mod weights;
 
 pub use module::*;
+pub use weights::WeightInfo;
 
 pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
@@ -53,8 +58,16 @@ pub mod module {
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
 		type Currency: Currency<Self::AccountId>;
 		type EVMBridge: EVMBridge<Self::AccountId, BalanceOf<Self>>;
+
+		/// The metadata of the foreign assets registered at runtime.
+		type ForeignAssetIdMapping: ForeignAssetIdMapping<ForeignAssetId, AssetMetadata<Balance>>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
 	}
 
 	/// Error for evm accounts module.
@@ -62,6 +75,19 @@ pub mod module {
 	pub enum Error<T> {
 		/// CurrencyId existed
 		CurrencyIdExisted,
+		/// The Erc20 address is in the reserved system contract range and can't
+		/// be mapped
+		ReservedErc20Address,
+		/// The Erc20 address has not been mapped
+		Erc20NotMapped,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The cached metadata of an Erc20 contract was fetched from the
+		/// contract. \[erc20_address, name, symbol, decimals\]
+		Erc20MetadataRefreshed(EvmAddress, Vec<u8>, Vec<u8>, u8),
 	}
 
 	/// Mapping between u32 and Erc20 address.
@@ -80,38 +106,76 @@ pub mod module {
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
 
 	#[pallet::call]
-	impl<T: Config> Pallet<T> {}
+	impl<T: Config> Pallet<T> {
+		/// Fetch the name, symbol and decimals of a mapped Erc20 contract
+		/// and replace the cached ones.
+		///
+		/// Only the contracts already in `CurrencyIdMap` can be refreshed, the
+		/// mapping itself is added when the contract is deployed. The caller
+		/// pays for the calls into the contract.
+		#[pallet::weight(T::WeightInfo::refresh_erc20_metadata())]
+		#[transactional]
+		pub fn refresh_erc20_metadata(origin: OriginFor<T>, address: EvmAddress) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let info = CurrencyIdMap::<T>::try_mutate(
+				Into::<u32>::into(DexShare::Erc20(address)),
+				|maybe_erc20_info| -> Result<Erc20Info, DispatchError> {
+					let erc20_info = maybe_erc20_info.as_mut().ok_or(Error::<T>::Erc20NotMapped)?;
+					ensure!(erc20_info.address == address, Error::<T>::Erc20NotMapped);
+
+					let info = Self::fetch_erc20_info(address)?;
+					*erc20_info = info.clone();
+					Ok(info)
+				},
+			)?;
+
+			Self::deposit_event(Event::Erc20MetadataRefreshed(
+				address,
+				info.name,
+				info.symbol,
+				info.decimals,
+			));
+			Ok(())
+		}
+	}
 }
 
-impl<T: Config> Pallet<T> {}
+impl<T: Config> Pallet<T> {
+	fn fetch_erc20_info(address: EvmAddress) -> Result<Erc20Info, DispatchError> {
+		let invoke_context = InvokeContext {
+			contract: address,
+			sender: Default::default(),
+			origin: Default::default(),
+		};
+
+		Ok(Erc20Info {
+			address,
+			name: T::EVMBridge::name(invoke_context)?,
+			symbol: T::EVMBridge::symbol(invoke_context)?,
+			decimals: T::EVMBridge::decimals(invoke_context)?,
+		})
+	}
+}
 
 pub struct EvmCurrencyIdMapping<T>(sp_std::marker::PhantomData<T>);
 
 impl<T: Config> CurrencyIdMapping for EvmCurrencyIdMapping<T> {
 	// Use first 4 non-zero bytes as u32 to the mapping between u32 and evm address.
 	// Take the first 4 non-zero bytes, if it is less than 4, add 0 to the left.
+	// The ids starting with a zero byte are left to the Token and ForeignAsset dex shares.
 	#[require_transactional]
 	fn set_erc20_mapping(address: EvmAddress) -> DispatchResult {
+		let id = Into::<u32>::into(DexShare::Erc20(address));
+		ensure!(id.to_be_bytes()[0] != 0, Error::<T>::ReservedErc20Address);
+
 		CurrencyIdMap::<T>::mutate(
-			Into::<u32>::into(DexShare::Erc20(address)),
+			id,
 			|maybe_erc20_info| -> DispatchResult {
 				if let Some(erc20_info) = maybe_erc20_info.as_mut() {
 					ensure!(erc20_info.address == address, Error::<T>::CurrencyIdExisted);
 				} else {
-					let invoke_context = InvokeContext {
-						contract: address,
-						sender: Default::default(),
-						origin: Default::default(),
-					};
-
-					let info = Erc20Info {
-						address,
-						name: T::EVMBridge::name(invoke_context)?,
-						symbol: T::EVMBridge::symbol(invoke_context)?,
-						decimals: T::EVMBridge::decimals(invoke_context)?,
-					};
-
-					*maybe_erc20_info = Some(info);
+					*maybe_erc20_info = Some(Pallet::<T>::fetch_erc20_info(address)?);
 				}
 				Ok(())
 			},
@@ -135,12 +199,18 @@ impl<T: Config> CurrencyIdMapping for EvmCurrencyIdMapping<T> {
 					DexShare::Erc20(address) => CurrencyIdMap::<T>::get(Into::<u32>::into(symbol_0))
 						.filter(|v| v.address == address)
 						.map(|v| v.name),
+					DexShare::ForeignAsset(foreign_asset_id) => {
+						T::ForeignAssetIdMapping::get_asset_metadata(foreign_asset_id).map(|v| v.name)
+					}
 				}?;
 				let name_1 = match symbol_1 {
 					DexShare::Token(symbol) => CurrencyId::Token(symbol).name().map(|v| v.as_bytes().to_vec()),
 					DexShare::Erc20(address) => CurrencyIdMap::<T>::get(Into::<u32>::into(symbol_1))
 						.filter(|v| v.address == address)
 						.map(|v| v.name),
+					DexShare::ForeignAsset(foreign_asset_id) => {
+						T::ForeignAssetIdMapping::get_asset_metadata(foreign_asset_id).map(|v| v.name)
+					}
 				}?;
 
 				let mut vec = Vec::new();
@@ -153,6 +223,10 @@ impl<T: Config> CurrencyIdMapping for EvmCurrencyIdMapping<T> {
 			CurrencyId::Erc20(address) => CurrencyIdMap::<T>::get(Into::<u32>::into(DexShare::Erc20(address)))
 				.filter(|v| v.address == address)
 				.map(|v| v.name),
+			CurrencyId::StableAssetPoolToken(_) => currency_id.name().map(|v| v.as_bytes().to_vec()),
+			CurrencyId::ForeignAsset(foreign_asset_id) => {
+				T::ForeignAssetIdMapping::get_asset_metadata(foreign_asset_id).map(|v| v.name)
+			}
 		}?;
 
 		// More than 32 bytes will be truncated.
@@ -175,12 +249,18 @@ impl<T: Config> CurrencyIdMapping for EvmCurrencyIdMapping<T> {
 					DexShare::Erc20(address) => CurrencyIdMap::<T>::get(Into::<u32>::into(symbol_0))
 						.filter(|v| v.address == address)
 						.map(|v| v.symbol),
+					DexShare::ForeignAsset(foreign_asset_id) => {
+						T::ForeignAssetIdMapping::get_asset_metadata(foreign_asset_id).map(|v| v.symbol)
+					}
 				}?;
 				let token_symbol_1 = match symbol_1 {
 					DexShare::Token(symbol) => CurrencyId::Token(symbol).symbol().map(|v| v.as_bytes().to_vec()),
 					DexShare::Erc20(address) => CurrencyIdMap::<T>::get(Into::<u32>::into(symbol_1))
 						.filter(|v| v.address == address)
 						.map(|v| v.symbol),
+					DexShare::ForeignAsset(foreign_asset_id) => {
+						T::ForeignAssetIdMapping::get_asset_metadata(foreign_asset_id).map(|v| v.symbol)
+					}
 				}?;
 
 				let mut vec = Vec::new();
@@ -193,6 +273,27 @@ impl<T: Config> CurrencyIdMapping for EvmCurrencyIdMapping<T> {
 			CurrencyId::Erc20(address) => CurrencyIdMap::<T>::get(Into::<u32>::into(DexShare::Erc20(address)))
 				.filter(|v| v.address == address)
 				.map(|v| v.symbol),
+			CurrencyId::StableAssetPoolToken(pool_id) => {
+				// SA_{pool_id}, pool id in decimal digits
+				let mut digits = Vec::new();
+				let mut id = pool_id;
+				loop {
+					digits.push(b'0' + (id % 10) as u8);
+					id /= 10;
+					if id == 0 {
+						break;
+					}
+				}
+				digits.reverse();
+
+				let mut vec = Vec::new();
+				vec.extend_from_slice(&b"SA_"[..]);
+				vec.extend_from_slice(&digits);
+				Some(vec)
+			}
+			CurrencyId::ForeignAsset(foreign_asset_id) => {
+				T::ForeignAssetIdMapping::get_asset_metadata(foreign_asset_id).map(|v| v.symbol)
+			}
 		}?;
 
 		// More than 32 bytes will be truncated.
@@ -217,11 +318,18 @@ impl<T: Config> CurrencyIdMapping for EvmCurrencyIdMapping<T> {
 					DexShare::Erc20(address) => CurrencyIdMap::<T>::get(Into::<u32>::into(symbol_0))
 						.filter(|v| v.address == address)
 						.map(|v| v.decimals),
+					DexShare::ForeignAsset(foreign_asset_id) => {
+						T::ForeignAssetIdMapping::get_asset_metadata(foreign_asset_id).map(|v| v.decimals)
+					}
 				}
 			}
 			CurrencyId::Erc20(address) => CurrencyIdMap::<T>::get(Into::<u32>::into(DexShare::Erc20(address)))
 				.filter(|v| v.address == address)
 				.map(|v| v.decimals),
+			CurrencyId::StableAssetPoolToken(_) => currency_id.decimals(),
+			CurrencyId::ForeignAsset(foreign_asset_id) => {
+				T::ForeignAssetIdMapping::get_asset_metadata(foreign_asset_id).map(|v| v.decimals)
+			}
 		}
 	}
 
@@ -233,6 +341,9 @@ impl<T: Config> CurrencyIdMapping for EvmCurrencyIdMapping<T> {
 			CurrencyId::DexShare(left, right) => {
 				let symbol_0 = match left {
 					DexShare::Token(_) => Some(left.into()),
+					DexShare::ForeignAsset(foreign_asset_id) => {
+						T::ForeignAssetIdMapping::get_asset_metadata(foreign_asset_id).map(|_| left.into())
+					}
 					DexShare::Erc20(address) => {
 						let id: u32 = left.into();
 						CurrencyIdMap::<T>::get(id).filter(|v| v.address == address).map(|_| id)
@@ -240,6 +351,9 @@ impl<T: Config> CurrencyIdMapping for EvmCurrencyIdMapping<T> {
 				}?;
 				let symbol_1 = match right {
 					DexShare::Token(_) => Some(right.into()),
+					DexShare::ForeignAsset(foreign_asset_id) => {
+						T::ForeignAssetIdMapping::get_asset_metadata(foreign_asset_id).map(|_| right.into())
+					}
 					DexShare::Erc20(address) => {
 						let id: u32 = right.into();
 						CurrencyIdMap::<T>::get(id).filter(|v| v.address == address).map(|_| id)
@@ -259,6 +373,8 @@ impl<T: Config> CurrencyIdMapping for EvmCurrencyIdMapping<T> {
 	// Decode the CurrencyId from EvmAddress.
 	// If is CurrencyId::DexShare and contain DexShare::Erc20,
 	// will use the u32 to get the DexShare::Erc20 from the mapping.
+	// Foreign assets must be registered, and addresses that aren't the
+	// canonical encoding of a CurrencyId decode to None.
 	fn decode_evm_address(addr: EvmAddress) -> Option<CurrencyId> {
 		let address = addr.as_bytes();
 
@@ -270,35 +386,74 @@ impl<T: Config> CurrencyIdMapping for EvmCurrencyIdMapping<T> {
 		// DexShare
 		if address.starts_with(&H160_PREFIX_DEXSHARE) {
 			let left = {
-				if address[H160_POSITION_DEXSHARE_LEFT].starts_with(&[0u8; 3]) {
+				if address[H160_POSITION_DEXSHARE_LEFT][0] != 0 {
+					// Erc20
+					let id = u32::from_be_bytes(address[H160_POSITION_DEXSHARE_LEFT].try_into().ok()?);
+					CurrencyIdMap::<T>::get(id).map(|v| DexShare::Erc20(v.address))
+				} else if address[H160_POSITION_DEXSHARE_LEFT].starts_with(&[0u8; 3]) {
 					// Token
 					address[H160_POSITION_DEXSHARE_LEFT][3]
 						.try_into()
 						.map(DexShare::Token)
 						.ok()
+				} else if address[H160_POSITION_DEXSHARE_LEFT][1] == DexShareType::ForeignAsset.into() {
+					// ForeignAsset
+					let id = u16::from_be_bytes(address[H160_POSITION_DEXSHARE_LEFT][2..].try_into().ok()?);
+					T::ForeignAssetIdMapping::get_asset_metadata(id).map(|_| DexShare::ForeignAsset(id))
 				} else {
-					// Erc20
-					let id = u32::from_be_bytes(address[H160_POSITION_DEXSHARE_LEFT].try_into().ok()?);
-					CurrencyIdMap::<T>::get(id).map(|v| DexShare::Erc20(v.address))
+					None
 				}
 			}?;
 			let right = {
-				if address[H160_POSITION_DEXSHARE_RIGHT].starts_with(&[0u8; 3]) {
+				if address[H160_POSITION_DEXSHARE_RIGHT][0] != 0 {
+					// Erc20
+					let id = u32::from_be_bytes(address[H160_POSITION_DEXSHARE_RIGHT].try_into().ok()?);
+					CurrencyIdMap::<T>::get(id).map(|v| DexShare::Erc20(v.address))
+				} else if address[H160_POSITION_DEXSHARE_RIGHT].starts_with(&[0u8; 3]) {
 					// Token
 					address[H160_POSITION_DEXSHARE_RIGHT][3]
 						.try_into()
 						.map(DexShare::Token)
 						.ok()
+				} else if address[H160_POSITION_DEXSHARE_RIGHT][1] == DexShareType::ForeignAsset.into() {
+					// ForeignAsset
+					let id = u16::from_be_bytes(address[H160_POSITION_DEXSHARE_RIGHT][2..].try_into().ok()?);
+					T::ForeignAssetIdMapping::get_asset_metadata(id).map(|_| DexShare::ForeignAsset(id))
 				} else {
-					// Erc20
-					let id = u32::from_be_bytes(address[H160_POSITION_DEXSHARE_RIGHT].try_into().ok()?);
-					CurrencyIdMap::<T>::get(id).map(|v| DexShare::Erc20(v.address))
+					None
 				}
 			}?;
 
 			return Some(CurrencyId::DexShare(left, right));
 		}
 
+		// StableAssetPoolToken
+		if address.starts_with(&H160_PREFIX_STABLE_ASSET) {
+			// the bytes between the prefix and the pool id must be zero
+			if address[H160_PREFIX_STABLE_ASSET.len()..H160_POSITION_STABLE_ASSET.start]
+				.iter()
+				.any(|b| *b != 0)
+			{
+				return None;
+			}
+			let pool_id = u32::from_be_bytes(address[H160_POSITION_STABLE_ASSET].try_into().ok()?);
+			return Some(CurrencyId::StableAssetPoolToken(pool_id));
+		}
+
+		// ForeignAsset
+		if address.starts_with(&H160_PREFIX_FOREIGN_ASSET) {
+			// the bytes between the prefix and the foreign asset id must be zero
+			if address[H160_PREFIX_FOREIGN_ASSET.len()..H160_POSITION_FOREIGN_ASSET.start]
+				.iter()
+				.any(|b| *b != 0)
+			{
+				return None;
+			}
+			let foreign_asset_id = u16::from_be_bytes(address[H160_POSITION_FOREIGN_ASSET].try_into().ok()?);
+			return T::ForeignAssetIdMapping::get_asset_metadata(foreign_asset_id)
+				.map(|_| CurrencyId::ForeignAsset(foreign_asset_id));
+		}
+
 		// Erc20
 		let id = Into::<u32>::into(DexShare::Erc20(addr));
 		CurrencyIdMap::<T>::get(id).map(|v| CurrencyId::Erc20(v.address))
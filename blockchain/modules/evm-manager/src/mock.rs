@@ -25,9 +25,9 @@
 use super::*;
 use frame_support::{assert_ok, construct_runtime, ord_parameter_types, parameter_types};
 use frame_system::EnsureSignedBy;
-use module_support::{mocks::MockAddressMapping, AddressMapping};
+use module_support::{mocks::MockAddressMapping, AddressMapping, ForeignAssetIdMapping};
 use orml_traits::parameter_type_with_key;
-use primitives::{Amount, Balance, CurrencyId, ReserveIdentifier, TokenSymbol};
+use primitives::{Amount, AssetMetadata, Balance, CurrencyId, ForeignAssetId, ReserveIdentifier, TokenSymbol};
 use sp_core::{bytes::from_hex, crypto::AccountId32, H160, H256};
 use sp_runtime::{testing::Header, traits::IdentityLookup};
 use std::str::FromStr;
@@ -168,9 +168,30 @@ impl module_evm_bridge::Config for Runtime {
 	type EVM = EVM;
 }
 
+pub const FOREIGN_ASSET_ID: ForeignAssetId = 1;
+
+pub struct MockForeignAssetIdMapping;
+impl ForeignAssetIdMapping<ForeignAssetId, AssetMetadata<Balance>> for MockForeignAssetIdMapping {
+	fn get_asset_metadata(foreign_asset_id: ForeignAssetId) -> Option<AssetMetadata<Balance>> {
+		if foreign_asset_id == FOREIGN_ASSET_ID {
+			Some(AssetMetadata {
+				name: b"Foreign Token".to_vec(),
+				symbol: b"FT".to_vec(),
+				decimals: 12,
+				minimal_balance: 1,
+			})
+		} else {
+			None
+		}
+	}
+}
+
 impl Config for Runtime {
+	type Event = Event;
 	type Currency = Balances;
 	type EVMBridge = EVMBridge;
+	type ForeignAssetIdMapping = MockForeignAssetIdMapping;
+	type WeightInfo = ();
 }
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
@@ -200,7 +221,7 @@ construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
 		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
-		EvmManager: evm_manager::{Pallet, Storage},
+		EvmManager: evm_manager::{Pallet, Call, Storage, Event<T>},
 		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Currencies: orml_currencies::{Pallet, Call, Event<T>},
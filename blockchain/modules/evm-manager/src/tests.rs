@@ -24,10 +24,14 @@
 
 use super::*;
 use frame_support::{assert_noop, assert_ok};
-use mock::{alice, deploy_contracts, erc20_address, erc20_address_not_exists, ExtBuilder, Runtime};
+use mock::{
+	alice, deploy_contracts, erc20_address, erc20_address_not_exists, Event, EvmManager, ExtBuilder, Origin, Runtime, System,
+	FOREIGN_ASSET_ID,
+};
 use orml_utilities::with_transaction_result;
 use primitives::TokenSymbol;
 use sp_core::H160;
+use sp_runtime::traits::BadOrigin;
 use std::str::FromStr;
 
 #[test]
@@ -69,6 +73,16 @@ fn set_erc20_mapping_works() {
 				}),
 				module_evm_bridge::Error::<Runtime>::InvalidReturnValue,
 			);
+
+			// the id would be the same as DexShare::ForeignAsset(1)
+			assert_noop!(
+				with_transaction_result(|| -> DispatchResult {
+					EvmCurrencyIdMapping::<Runtime>::set_erc20_mapping(
+						EvmAddress::from_str("0000000000000000000000000000000000020001").unwrap(),
+					)
+				}),
+				Error::<Runtime>::ReservedErc20Address,
+			);
 		});
 }
 
@@ -205,6 +219,16 @@ fn symbol_works() {
 				)),
 				None
 			);
+
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::symbol(CurrencyId::StableAssetPoolToken(0)),
+				Some(b"SA_0".to_vec())
+			);
+
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::symbol(CurrencyId::StableAssetPoolToken(120)),
+				Some(b"SA_120".to_vec())
+			);
 		});
 }
 
@@ -413,6 +437,32 @@ fn decode_evm_address_works() {
 				))
 			);
 
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::decode_evm_address(
+					EvmCurrencyIdMapping::<Runtime>::encode_evm_address(CurrencyId::StableAssetPoolToken(1)).unwrap()
+				),
+				Some(CurrencyId::StableAssetPoolToken(1))
+			);
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::encode_evm_address(CurrencyId::StableAssetPoolToken(u32::MAX)),
+				Some(H160::from_str("0x00000000000000000000000200000000ffffffff").unwrap())
+			);
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::decode_evm_address(
+					H160::from_str("0x00000000000000000000000200000000ffffffff").unwrap()
+				),
+				Some(CurrencyId::StableAssetPoolToken(u32::MAX))
+			);
+
+			// decode non-canonical evm address
+			// CurrencyId::StableAssetPoolToken(1) with non-zero padding
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::decode_evm_address(
+					H160::from_str("0x0000000000000000000000020000000100000001").unwrap()
+				),
+				None
+			);
+
 			// decode invalid evm address
 			// CurrencyId::DexShare(DexShare::Token(TokenSymbol::SEE),
 			// DexShare::Erc20(erc20_address_not_exists()))
@@ -458,3 +508,164 @@ fn decode_evm_address_works() {
 			);
 		});
 }
+
+#[test]
+fn foreign_asset_works() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 1_000_000_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			let foreign_asset = CurrencyId::ForeignAsset(FOREIGN_ASSET_ID);
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::name(foreign_asset),
+				Some(b"Foreign Token".to_vec())
+			);
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::symbol(foreign_asset),
+				Some(b"FT".to_vec())
+			);
+			assert_eq!(EvmCurrencyIdMapping::<Runtime>::decimals(foreign_asset), Some(12));
+
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::symbol(CurrencyId::DexShare(
+					DexShare::ForeignAsset(FOREIGN_ASSET_ID),
+					DexShare::Token(TokenSymbol::SETUSD)
+				)),
+				Some(b"LP_FT_SETUSD".to_vec())
+			);
+
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::name(CurrencyId::ForeignAsset(FOREIGN_ASSET_ID + 1)),
+				None
+			);
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::encode_evm_address(CurrencyId::DexShare(
+					DexShare::ForeignAsset(FOREIGN_ASSET_ID + 1),
+					DexShare::Token(TokenSymbol::SETUSD)
+				)),
+				None
+			);
+
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::encode_evm_address(foreign_asset),
+				Some(H160::from_str("0x0000000000000000000000030000000000000001").unwrap())
+			);
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::decode_evm_address(
+					EvmCurrencyIdMapping::<Runtime>::encode_evm_address(foreign_asset).unwrap()
+				),
+				Some(foreign_asset)
+			);
+
+			let dex_share = CurrencyId::DexShare(
+				DexShare::ForeignAsset(FOREIGN_ASSET_ID),
+				DexShare::Token(TokenSymbol::SETUSD),
+			);
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::decode_evm_address(
+					EvmCurrencyIdMapping::<Runtime>::encode_evm_address(dex_share).unwrap()
+				),
+				Some(dex_share)
+			);
+
+			// unregistered foreign assets
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::decode_evm_address(
+					EvmCurrencyIdMapping::<Runtime>::encode_evm_address(CurrencyId::ForeignAsset(FOREIGN_ASSET_ID + 1))
+						.unwrap()
+				),
+				None
+			);
+			// CurrencyId::DexShare(DexShare::ForeignAsset(FOREIGN_ASSET_ID + 1), DexShare::Token(SETUSD))
+			let mut unregistered_dex_share = EvmCurrencyIdMapping::<Runtime>::encode_evm_address(dex_share).unwrap();
+			unregistered_dex_share[15] += 1;
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::decode_evm_address(unregistered_dex_share),
+				None
+			);
+
+			// decode non-canonical evm address
+			// CurrencyId::ForeignAsset(FOREIGN_ASSET_ID) with non-zero padding
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::decode_evm_address(
+					H160::from_str("0x0000000000000000000000030000000100000001").unwrap()
+				),
+				None
+			);
+
+			// unknown DexShareType tag
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::decode_evm_address(
+					H160::from_str("0x0000000000000000000000010003000100000005").unwrap()
+				),
+				None
+			);
+		});
+}
+
+#[test]
+fn refresh_erc20_metadata_works() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 1_000_000_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			System::set_block_number(1);
+
+			assert_noop!(
+				EvmManager::refresh_erc20_metadata(Origin::none(), erc20_address()),
+				BadOrigin
+			);
+
+			// unmapped contracts can't be added
+			assert_noop!(
+				EvmManager::refresh_erc20_metadata(Origin::signed(alice()), erc20_address()),
+				Error::<Runtime>::Erc20NotMapped
+			);
+			assert_ok!(with_transaction_result(|| -> DispatchResult {
+				EvmCurrencyIdMapping::<Runtime>::set_erc20_mapping(erc20_address())
+			}));
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::decimals(CurrencyId::Erc20(erc20_address())),
+				Some(17)
+			);
+
+			// stale metadata is refreshed from the contract
+			CurrencyIdMap::<Runtime>::mutate(Into::<u32>::into(DexShare::Erc20(erc20_address())), |maybe_erc20_info| {
+				if let Some(erc20_info) = maybe_erc20_info.as_mut() {
+					erc20_info.decimals = 1;
+				}
+			});
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::decimals(CurrencyId::Erc20(erc20_address())),
+				Some(1)
+			);
+			assert_ok!(EvmManager::refresh_erc20_metadata(
+				Origin::signed(alice()),
+				erc20_address()
+			));
+			System::assert_last_event(Event::EvmManager(crate::Event::Erc20MetadataRefreshed(
+				erc20_address(),
+				b"long string name, long string name, long string name, long string name, long string name".to_vec(),
+				b"TestToken".to_vec(),
+				17,
+			)));
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::decimals(CurrencyId::Erc20(erc20_address())),
+				Some(17)
+			);
+
+			// same id as the mapped contract
+			assert_noop!(
+				EvmManager::refresh_erc20_metadata(
+					Origin::signed(alice()),
+					EvmAddress::from_str("0000000000000000000000000000000200000000").unwrap()
+				),
+				Error::<Runtime>::Erc20NotMapped
+			);
+			assert_noop!(
+				EvmManager::refresh_erc20_metadata(Origin::signed(alice()), erc20_address_not_exists()),
+				Error::<Runtime>::Erc20NotMapped
+			);
+		});
+}
@@ -37,7 +37,7 @@ use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
 };
-use support::{mocks::MockAddressMapping, SerpTreasury};
+use support::{mocks::MockAddressMapping, SerpTreasuryInspect, SerpTreasuryMutate};
 
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
@@ -172,7 +172,7 @@ impl orml_tokens::Config for Runtime {
 }
 
 pub struct MockSerpTreasury;
-impl SerpTreasury<AccountId> for MockSerpTreasury {
+impl SerpTreasuryInspect<AccountId> for MockSerpTreasury {
 	type Balance = Balance;
 	type CurrencyId = CurrencyId;
 
@@ -184,6 +184,15 @@ impl SerpTreasury<AccountId> for MockSerpTreasury {
 		unimplemented!()
 	}
 
+	/// get the minimum supply of a setcurrency - by key
+	fn get_minimum_supply(
+		_currency_id: CurrencyId
+	) -> Balance {
+		unimplemented!()
+	}
+}
+
+impl SerpTreasuryMutate<AccountId> for MockSerpTreasury {
 	fn serp_tes_now() -> DispatchResult {
 		unimplemented!()
 	}
@@ -265,13 +274,6 @@ impl SerpTreasury<AccountId> for MockSerpTreasury {
 		unimplemented!()
 	}
 
-	/// get the minimum supply of a setcurrency - by key
-	fn get_minimum_supply(
-		_currency_id: CurrencyId
-	) -> Balance {
-		unimplemented!()
-	}
-
 	/// issue standard to `who`
 	fn issue_standard(
 		_currency_id: CurrencyId,
@@ -351,6 +353,7 @@ impl module_currencies::Config for Runtime {
 	type AddressMapping = MockAddressMapping;
 	type EVMBridge = ();
 	type SweepOrigin = EnsureSignedBy<One, AccountId>;
+	type DustRemovalWhitelistOrigin = EnsureSignedBy<One, AccountId>;
 	type OnDust = ();
 }
 
@@ -404,7 +407,7 @@ construct_runtime!(
 		Proxy: pallet_proxy::{Pallet, Call, Storage, Event<T>},
 		Utility: pallet_utility::{Pallet, Call, Event},
 		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
-		Currency: module_currencies::{Pallet, Call, Event<T>},
+		Currency: module_currencies::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
@@ -27,20 +27,37 @@
 //!   - specify a fixed price for stable currency
 //!   - feed price in USD or related price bewteen two currencies
 //!   - lock/unlock the price data get from oracle
+//!   - accumulate prices every block to provide time-weighted average prices (TWAP)
+//!   - accumulate the DEX market prices of stable currencies for TWAP, for TES
+//!   - reject stale prices that were not fed by the oracle within `MaxPriceAge`
+//!   - aggregate the feeds of multiple data sources by median or weighted mean
+//!   - reject anomalous prices outside the governed `PriceBounds` of a currency
+//!   - value SETR by a governed basket of weighted fiat pegs
+//!   - derive prices from DEX pool reserves against SETUSD when the oracle has none
+//!   - record a bounded history of combined oracle prices for moving averages
+//!   - raise info/warning/critical alarms when a stable currency's combined price
+//!     or DEX market price deviates from its peg
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
-use frame_support::{pallet_prelude::*, transactional};
+use frame_support::{
+	pallet_prelude::*, traits::StorageVersion, transactional, BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound,
+	RuntimeDebugNoBound,
+};
 use frame_system::pallet_prelude::*;
-use orml_traits::{DataFeeder, DataProvider, MultiCurrency};
+use orml_traits::{DataFeeder, DataProvider, MultiCurrency, OnNewData};
 use primitives::{Balance, CurrencyId};
 use sp_core::U256;
-use sp_runtime::FixedPointNumber;
-use sp_std::{convert::TryInto, marker::PhantomData};
-use support::{CurrencyIdMapping, DEXManager, LockablePrice, Price, PriceProvider};
+use sp_runtime::{
+	traits::{CheckedDiv, CheckedMul, CheckedSub, SaturatedConversion, Saturating, Zero},
+	FixedPointNumber, Permill,
+};
+use sp_std::{convert::TryInto, marker::PhantomData, prelude::*};
+use support::{CurrencyIdMapping, DEXManager, LockablePrice, Price, PriceProvider, TwapPriceProvider};
 use integer_sqrt::*;
 
+pub mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
@@ -48,10 +65,76 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// A set of data sources whose feeds can be aggregated into a price.
+pub trait DataProviderSet<Key, Value> {
+	/// Get the feed of `key` from every data source in the set, in order.
+	fn get_all(key: &Key) -> Vec<Option<Value>>;
+}
+
+impl<Key, Value> DataProviderSet<Key, Value> for () {
+	fn get_all(_key: &Key) -> Vec<Option<Value>> {
+		Vec::new()
+	}
+}
+
+macro_rules! impl_data_provider_set {
+	($($provider:ident),+) => {
+		impl<Key, Value, $($provider: DataProvider<Key, Value>),+> DataProviderSet<Key, Value> for ($($provider,)+) {
+			fn get_all(key: &Key) -> Vec<Option<Value>> {
+				sp_std::vec![$($provider::get(key)),+]
+			}
+		}
+	};
+}
+
+impl_data_provider_set!(A);
+impl_data_provider_set!(A, B);
+impl_data_provider_set!(A, B, C);
+impl_data_provider_set!(A, B, C, D);
+impl_data_provider_set!(A, B, C, D, E);
+
+/// The way the feeds of `Sources` are combined into a price.
+#[derive(Encode, Decode, CloneNoBound, RuntimeDebugNoBound, PartialEqNoBound, EqNoBound)]
+pub enum AggregationMethod<MaxFeeders: Get<u32>> {
+	/// The median of the available feeds.
+	Median,
+	/// The mean of the available feeds, weighted by the weight of their
+	/// data source in the order of `Sources`.
+	WeightedMean(BoundedVec<u32, MaxFeeders>),
+}
+
+/// Aggregation parameters of a currency's price.
+#[derive(Encode, Decode, CloneNoBound, RuntimeDebugNoBound, PartialEqNoBound, EqNoBound)]
+pub struct AggregationParams<MaxFeeders: Get<u32>> {
+	/// How the feeds are combined.
+	pub method: AggregationMethod<MaxFeeders>,
+	/// The minimum number of data sources that must have a feed
+	/// for the price to be valid.
+	pub min_feeders: u32,
+}
+
+/// How far a stable currency's fed or market price has deviated from its peg.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DeviationSeverity {
+	Info,
+	Warning,
+	Critical,
+}
+
+/// The deviations from the peg at which each `DeviationSeverity` is raised.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+pub struct DeviationThresholds {
+	pub info: Permill,
+	pub warning: Permill,
+	pub critical: Permill,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
 
+	pub type AggregationParamsOf<T> = AggregationParams<<T as Config>::MaxFeeders>;
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
@@ -59,6 +142,14 @@ pub mod module {
 		/// The data source, such as Oracle.
 		type Source: DataProvider<CurrencyId, Price> + DataFeeder<CurrencyId, Price, Self::AccountId>;
 
+		/// The data sources aggregated for currencies with `AggregationParams`.
+		type Sources: DataProviderSet<CurrencyId, Price>;
+
+		/// The maximum number of data sources in `Sources`, it bounds the
+		/// weights of `AggregationMethod::WeightedMean`.
+		#[pallet::constant]
+		type MaxFeeders: Get<u32>;
+
 		/// The stable currency id, it should be SETUSD in Setheum.
 		#[pallet::constant]
 		type GetSetUSDId: Get<CurrencyId>;
@@ -71,22 +162,64 @@ pub mod module {
 		#[pallet::constant]
 		type SetUSDFixedPrice: Get<Price>;
 
-		/// The fixed prices of stable currency SETR, it should be 0.1 USD (10 cents) in Setheum.
+		/// The fixed prices of stable currency SETR, it should be 0.1 USD (10 cents) in Setheum,
+		/// used when no `SetterBasket` is configured, and the price of SETR when the
+		/// basket is at parity with SETUSD otherwise.
 		#[pallet::constant]
 		type SetterFixedPrice: Get<Price>;
 
+		/// The maximum number of fiat pegs in the `SetterBasket`.
+		#[pallet::constant]
+		type MaxSetterBasketLength: Get<u32>;
+
 		/// The origin which may lock and unlock prices feed to system.
 		type LockOrigin: EnsureOrigin<Self::Origin>;
 
+		/// The origin which may update the price feed parameters.
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
 		/// DEX provide liquidity info.
 		type DEX: DEXManager<Self::AccountId, CurrencyId, Balance>;
 
+		/// The minimum SETUSD reserve of a DEX pool for its implied price to be used,
+		/// and the minimum stable currency reserve for its market price to be used,
+		/// this prevents thin pools from being manipulated into the system.
+		#[pallet::constant]
+		type MinimumDexLiquidity: Get<Balance>;
+
 		/// Currency provide the total insurance of LPToken.
 		type Currency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
 
 		/// Mapping between CurrencyId and ERC20 address so user can use Erc20.
 		type CurrencyIdMapping: CurrencyIdMapping;
 
+		/// The currencies whose prices are accumulated every block for TWAP.
+		type TwapCurrencyIds: Get<Vec<CurrencyId>>;
+
+		/// The stable currencies whose DEX market prices are accumulated every block for TWAP.
+		type StableCurrencyIds: Get<Vec<CurrencyId>>;
+
+		/// The currency the market prices of `StableCurrencyIds` are valued against,
+		/// by the reserves of their DEX pools with it and it's real-time price.
+		#[pallet::constant]
+		type GetMarketReferenceCurrencyId: Get<CurrencyId>;
+
+		/// The maximum number of cumulative price observations kept per currency,
+		/// this bounds the longest window a TWAP can be taken over.
+		#[pallet::constant]
+		type MaxTwapObservations: Get<u32>;
+
+		/// The number of blocks after its last feed that an oracle price
+		/// becomes stale and is no longer accessible, oracle prices whose
+		/// feed has never been recorded are stale.
+		#[pallet::constant]
+		type MaxPriceAge: Get<Self::BlockNumber>;
+
+		/// The maximum number of oracle prices kept per currency,
+		/// this bounds the most periods a moving average can be taken over.
+		#[pallet::constant]
+		type MaxPriceHistory: Get<u32>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -97,6 +230,14 @@ pub mod module {
 		AccessPriceFailed,
 		/// There's no locked price
 		NoLockedPrice,
+		/// The aggregation params don't match `Sources`
+		InvalidAggregationParams,
+		/// The min price bound is greater than the max price bound
+		InvalidPriceBounds,
+		/// The peg weights don't sum to 100%, or the basket has duplicate or too many pegs
+		InvalidSetterBasket,
+		/// The deviation thresholds are not in increasing order
+		InvalidDeviationThresholds,
 	}
 
 	#[pallet::event]
@@ -110,6 +251,24 @@ pub mod module {
 		FetchPrice(CurrencyId, Option<Price>),
 		/// Unlock price. \[relative_price\]
 		RelativePrice(CurrencyId, CurrencyId, Option<Price>),
+		/// Aggregation params updated. \[currency_id, aggregation_params\]
+		AggregationParamsUpdated(CurrencyId, Option<AggregationParamsOf<T>>),
+		/// Price bounds updated. \[currency_id, price_bounds\]
+		PriceBoundsUpdated(CurrencyId, Option<(Price, Price)>),
+		/// A fed price outside the price bounds was rejected. \[currency_id, price\]
+		AnomalousPriceRejected(CurrencyId, Price),
+		/// Setter basket updated. \[basket\]
+		SetterBasketUpdated(Vec<(CurrencyId, Permill)>),
+		/// Peg deviation thresholds updated. \[thresholds\]
+		PegDeviationThresholdsUpdated(Option<DeviationThresholds>),
+		/// A stable currency's fed price passed the info threshold. \[currency_id, price, deviation\]
+		PegDeviationInfo(CurrencyId, Price, Permill),
+		/// A stable currency's fed price passed the warning threshold. \[currency_id, price, deviation\]
+		PegDeviationWarning(CurrencyId, Price, Permill),
+		/// A stable currency's fed price passed the critical threshold. \[currency_id, price, deviation\]
+		PegDeviationCritical(CurrencyId, Price, Permill),
+		/// A stable currency's fed price is back within the info threshold. \[currency_id, price\]
+		PegDeviationResolved(CurrencyId, Price),
 	}
 
 	/// Mapping from currency id to it's locked price
@@ -119,11 +278,114 @@ pub mod module {
 	#[pallet::getter(fn locked_price)]
 	pub type LockedPrice<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Price, OptionQuery>;
 
+	/// Mapping from currency id to the block it's oracle price was last fed at
+	///
+	/// LastPriceUpdate: map CurrencyId => Option<BlockNumber>
+	#[pallet::storage]
+	#[pallet::getter(fn last_price_update)]
+	pub type LastPriceUpdate<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, T::BlockNumber, OptionQuery>;
+
+	/// Mapping from currency id to the params it's price is aggregated from `Sources` by,
+	/// if none, the price is taken from `Source` directly.
+	///
+	/// AggregationConfigs: map CurrencyId => Option<AggregationParams>
+	#[pallet::storage]
+	#[pallet::getter(fn aggregation_params)]
+	pub type AggregationConfigs<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, AggregationParamsOf<T>, OptionQuery>;
+
+	/// Mapping from currency id to the (min, max) band it's oracle price must be within
+	///
+	/// PriceBounds: map CurrencyId => Option<(Price, Price)>
+	#[pallet::storage]
+	#[pallet::getter(fn price_bounds)]
+	pub type PriceBounds<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, (Price, Price), OptionQuery>;
+
+	/// The fiat pegs and their weights that SETR is valued by,
+	/// if empty, SETR is valued at `SetterFixedPrice`.
+	///
+	/// SetterBasket: Vec<(CurrencyId, Permill)>
+	#[pallet::storage]
+	#[pallet::getter(fn setter_basket)]
+	pub type SetterBasket<T: Config> = StorageValue<_, Vec<(CurrencyId, Permill)>, ValueQuery>;
+
+	/// The deviations from the peg at which alarms are raised for SETUSD and SETR,
+	/// if none, no alarms are raised.
+	///
+	/// PegDeviationThresholds: Option<DeviationThresholds>
+	#[pallet::storage]
+	#[pallet::getter(fn peg_deviation_thresholds)]
+	pub type PegDeviationThresholds<T: Config> = StorageValue<_, DeviationThresholds, OptionQuery>;
+
+	/// The alarm currently raised for a stable currency's peg.
+	///
+	/// PegDeviationSeverities: map CurrencyId => Option<DeviationSeverity>
+	#[pallet::storage]
+	#[pallet::getter(fn peg_deviation_severity)]
+	pub type PegDeviationSeverities<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, DeviationSeverity, OptionQuery>;
+
+	/// The recent oracle prices recorded for a currency, one per block it was fed in, oldest first.
+	///
+	/// PriceHistory: map CurrencyId => BoundedVec<Price, MaxPriceHistory>
+	#[pallet::storage]
+	#[pallet::getter(fn price_history)]
+	pub type PriceHistory<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, BoundedVec<Price, T::MaxPriceHistory>, ValueQuery>;
+
+	/// The currencies the data source got new feeds for in the current block,
+	/// their combined prices are recorded on finalize.
+	///
+	/// FedCurrencyIds: Vec<CurrencyId>
+	#[pallet::storage]
+	pub type FedCurrencyIds<T: Config> = StorageValue<_, Vec<CurrencyId>, ValueQuery>;
+
+	/// Cumulative price observations of the TWAP currencies and the price observed
+	/// with each, oldest first. Stable currencies are observed by their market price.
+	///
+	/// TwapObservations: map CurrencyId => BoundedVec<(BlockNumber, CumulativePrice, Price), MaxTwapObservations>
+	#[pallet::storage]
+	#[pallet::getter(fn twap_observations)]
+	pub type TwapObservations<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		CurrencyId,
+		BoundedVec<(T::BlockNumber, Price, Price), T::MaxTwapObservations>,
+		ValueQuery,
+	>;
+
+	/// The current storage version, bumped to 1 by `migrations::RenameCurrencyIds`.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Accumulate the prices of `TwapCurrencyIds` and the market prices of `StableCurrencyIds`.
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let currency_ids: Vec<CurrencyId> = T::TwapCurrencyIds::get()
+				.into_iter()
+				.chain(T::StableCurrencyIds::get())
+				.collect();
+			for currency_id in currency_ids.iter() {
+				Self::accumulate_price(*currency_id, now);
+			}
+			// check the DEX market prices of the stable currencies between feeds
+			for currency_id in T::StableCurrencyIds::get() {
+				Self::check_peg_deviation(currency_id);
+			}
+			// the weight of each fed currency is registered by `on_new_data`
+			T::WeightInfo::on_initialize(currency_ids.len() as u32).saturating_add(T::WeightInfo::on_finalize(0))
+		}
+
+		/// Record the combined prices of the currencies fed in this block.
+		fn on_finalize(now: T::BlockNumber) {
+			for currency_id in FedCurrencyIds::<T>::take() {
+				Self::record_price(currency_id, now);
+			}
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -152,6 +414,132 @@ pub mod module {
 			<Pallet<T> as LockablePrice<CurrencyId>>::unlock_price(currency_id)?;
 			Ok(())
 		}
+
+		/// Aggregate the price of `currency_id` from `Sources`, or take it
+		/// from `Source` directly if `aggregation_params` is none.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: currency type.
+		/// - `aggregation_params`: the aggregation method and minimum feeders.
+		#[pallet::weight((T::WeightInfo::set_aggregation_params(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_aggregation_params(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			aggregation_params: Option<AggregationParamsOf<T>>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			if let Some(params) = &aggregation_params {
+				let sources_count = T::Sources::get_all(&currency_id).len();
+				ensure!(
+					params.min_feeders as usize <= sources_count,
+					Error::<T>::InvalidAggregationParams
+				);
+				if let AggregationMethod::WeightedMean(weights) = &params.method {
+					ensure!(
+						weights.len() == sources_count && weights.iter().any(|weight| !weight.is_zero()),
+						Error::<T>::InvalidAggregationParams
+					);
+				}
+			}
+
+			AggregationConfigs::<T>::mutate_exists(currency_id, |maybe_params| {
+				*maybe_params = aggregation_params.clone();
+			});
+			Self::deposit_event(Event::AggregationParamsUpdated(currency_id, aggregation_params));
+			Ok(())
+		}
+
+		/// Reject the oracle prices of `currency_id` outside `price_bounds`,
+		/// or remove the bounds if `price_bounds` is none.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: currency type.
+		/// - `price_bounds`: the (min, max) band of the price.
+		#[pallet::weight((T::WeightInfo::set_price_bounds(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_price_bounds(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			price_bounds: Option<(Price, Price)>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			if let Some((min, max)) = price_bounds {
+				ensure!(min <= max, Error::<T>::InvalidPriceBounds);
+			}
+
+			PriceBounds::<T>::mutate_exists(currency_id, |maybe_bounds| {
+				*maybe_bounds = price_bounds;
+			});
+			Self::deposit_event(Event::PriceBoundsUpdated(currency_id, price_bounds));
+			Ok(())
+		}
+
+		/// Value SETR by the weighted prices of `basket`,
+		/// or by `SetterFixedPrice` if `basket` is empty.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `basket`: the fiat pegs and their weights, the weights must sum to 100%.
+		#[pallet::weight((T::WeightInfo::set_setter_basket(basket.len() as u32), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_setter_basket(origin: OriginFor<T>, basket: Vec<(CurrencyId, Permill)>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			if !basket.is_empty() {
+				ensure!(
+					basket.len() as u32 <= T::MaxSetterBasketLength::get(),
+					Error::<T>::InvalidSetterBasket
+				);
+				let total_weight = basket
+					.iter()
+					.try_fold(0u32, |total, (_, weight)| total.checked_add(weight.deconstruct()));
+				ensure!(
+					total_weight == Some(Permill::one().deconstruct()),
+					Error::<T>::InvalidSetterBasket
+				);
+				for (i, (peg, _)) in basket.iter().enumerate() {
+					ensure!(
+						*peg != T::SetterCurrencyId::get() && !basket[..i].iter().any(|(other, _)| other == peg),
+						Error::<T>::InvalidSetterBasket
+					);
+				}
+			}
+
+			SetterBasket::<T>::put(basket.clone());
+			Self::deposit_event(Event::SetterBasketUpdated(basket));
+			Ok(())
+		}
+
+		/// Raise alarms when a stable currency's fed price deviates from
+		/// its peg by `thresholds`, or stop raising them if `thresholds` is none.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `thresholds`: the info, warning and critical deviations, in increasing order.
+		#[pallet::weight((T::WeightInfo::set_peg_deviation_thresholds(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_peg_deviation_thresholds(
+			origin: OriginFor<T>,
+			thresholds: Option<DeviationThresholds>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			if let Some(t) = thresholds {
+				ensure!(
+					t.info <= t.warning && t.warning <= t.critical,
+					Error::<T>::InvalidDeviationThresholds
+				);
+			}
+
+			PegDeviationThresholds::<T>::set(thresholds);
+			Self::deposit_event(Event::PegDeviationThresholdsUpdated(thresholds));
+			Ok(())
+		}
 	}
 }
 
@@ -165,8 +553,8 @@ impl<T: Config> Pallet<T> {
 			// if is SETUSD, use fixed price
 			Some(T::SetUSDFixedPrice::get())
 		} else if currency_id == T::SetterCurrencyId::get() {
-			// if is SETR, return Setter fixed price (currently $0.1)
-			Some(T::SetterFixedPrice::get())
+			// if is SETR, return Setter basket price, or fixed price (currently $0.1) if no basket
+			Self::setter_price()
 		} else if let CurrencyId::DexShare(symbol_0, symbol_1) = currency_id {
 			let token_0: CurrencyId = symbol_0.into();
 			let token_1: CurrencyId = symbol_1.into();
@@ -182,8 +570,15 @@ impl<T: Config> Pallet<T> {
 				}
 			};
 		} else {
-			// get real-time price from oracle
-			T::Source::get(&currency_id)
+			// get real-time price from oracle, or derive it from DEX if oracle has never had a feed,
+			// a stale or anomalous oracle price is not replaced by the DEX spot price
+			Self::oracle_price(currency_id).or_else(|| {
+				if Self::has_oracle_feed(currency_id) {
+					None
+				} else {
+					Self::get_dex_implied_price(currency_id)
+				}
+			})
 		};
 
 		let maybe_adjustment_multiplier = 10u128.checked_pow(T::CurrencyIdMapping::decimals(currency_id)?.into());
@@ -195,6 +590,347 @@ impl<T: Config> Pallet<T> {
 			None
 		}
 	}
+
+	/// Get the price of `currency_id` from the data source,
+	/// return `None` if it is stale or outside the price bounds.
+	fn oracle_price(currency_id: CurrencyId) -> Option<Price> {
+		if Self::is_price_stale(currency_id) {
+			return None;
+		}
+
+		Self::source_price(currency_id).filter(|price| Self::is_within_bounds(currency_id, *price))
+	}
+
+	/// Get the combined feed of `currency_id`, aggregated from `Sources` if it
+	/// has `AggregationParams`, or taken from `Source` directly otherwise.
+	fn source_price(currency_id: CurrencyId) -> Option<Price> {
+		if let Some(params) = Self::aggregation_params(currency_id) {
+			Self::aggregate_price(currency_id, params)
+		} else {
+			T::Source::get(&currency_id)
+		}
+	}
+
+	/// Whether the data source has ever had a feed for `currency_id`.
+	fn has_oracle_feed(currency_id: CurrencyId) -> bool {
+		Self::last_price_update(currency_id).is_some() || Self::source_price(currency_id).is_some()
+	}
+
+	/// Derive the price of `currency_id` from the reserves of it's DEX pool
+	/// against SETUSD, return `None` if the SETUSD reserve is below
+	/// `MinimumDexLiquidity` or the price is outside the price bounds.
+	pub fn get_dex_implied_price(currency_id: CurrencyId) -> Option<Price> {
+		let stable_currency_id = T::GetSetUSDId::get();
+		let (stable_reserve, token_reserve) = T::DEX::get_liquidity_pool(stable_currency_id, currency_id);
+		if stable_reserve < T::MinimumDexLiquidity::get() || token_reserve.is_zero() {
+			return None;
+		}
+
+		// the reserves are in basic units, shift them to the decimals of the currencies
+		let decimals_adjustment = Price::checked_from_rational(
+			10u128.checked_pow(T::CurrencyIdMapping::decimals(currency_id)?.into())?,
+			10u128.checked_pow(T::CurrencyIdMapping::decimals(stable_currency_id)?.into())?,
+		)?;
+		let price = Price::checked_from_rational(stable_reserve, token_reserve)?
+			.checked_mul(&decimals_adjustment)?
+			.checked_mul(&T::SetUSDFixedPrice::get())?;
+
+		Some(price).filter(|price| Self::is_within_bounds(currency_id, *price))
+	}
+
+	/// Get the market price of a stable currency for 1 basic unit, valued by the
+	/// reserves of it's DEX pool against `GetMarketReferenceCurrencyId` at the
+	/// real-time price of the reference currency, return `None` if the stable
+	/// currency reserve is below `MinimumDexLiquidity`.
+	///
+	/// Note: this is a spot price that can be moved within a block, the TWAP
+	/// of a stable currency is of this price and should be used instead.
+	pub fn get_market_price(currency_id: CurrencyId) -> Option<Price> {
+		let reference_currency_id = T::GetMarketReferenceCurrencyId::get();
+		let (reference_reserve, stable_reserve) = T::DEX::get_liquidity_pool(reference_currency_id, currency_id);
+		if stable_reserve < T::MinimumDexLiquidity::get() {
+			return None;
+		}
+
+		Price::checked_from_rational(reference_reserve, stable_reserve)?
+			.checked_mul(&Self::access_price(reference_currency_id)?)
+	}
+
+	/// Get the price of `currency_id` accumulated for TWAP, which is the market
+	/// price for `StableCurrencyIds` and the real-time price otherwise.
+	fn twap_observed_price(currency_id: CurrencyId) -> Option<Price> {
+		if T::StableCurrencyIds::get().contains(&currency_id) {
+			Self::get_market_price(currency_id)
+		} else {
+			Self::access_price(currency_id)
+		}
+	}
+
+	/// Get the mean of the last `periods` oracle prices of `currency_id` for 1 basic unit,
+	/// return `None` if fewer than `periods` prices have been recorded.
+	pub fn get_moving_average_price(currency_id: CurrencyId, periods: u32) -> Option<Price> {
+		let history = Self::price_history(currency_id);
+		let periods = periods as usize;
+		if periods.is_zero() || history.len() < periods {
+			return None;
+		}
+
+		let sum = history[history.len() - periods..]
+			.iter()
+			.fold(Price::zero(), |sum, price| sum.saturating_add(*price));
+		let average_price = sum.checked_div(&Price::saturating_from_integer(periods as u128))?;
+
+		let adjustment_multiplier = 10u128.checked_pow(T::CurrencyIdMapping::decimals(currency_id)?.into())?;
+		Price::checked_from_rational(average_price.into_inner(), adjustment_multiplier)
+	}
+
+	/// Get the price of SETR by the `SetterBasket`, return `None` if any peg
+	/// has no oracle price.
+	///
+	/// The weighted sum of the peg prices is scaled by `SetterFixedPrice` over
+	/// `SetUSDFixedPrice`, so SETR is valued at `SetterFixedPrice` when the
+	/// basket is at parity with SETUSD.
+	pub fn get_setter_basket_price() -> Option<Price> {
+		let basket = Self::setter_basket();
+		if basket.is_empty() {
+			return None;
+		}
+
+		let basket_price = basket.into_iter().try_fold(Price::zero(), |basket_price, (peg, weight)| {
+			let peg_price = Self::oracle_price(peg)?;
+			Some(basket_price.saturating_add(Price::from_inner(weight.mul_floor(peg_price.into_inner()))))
+		})?;
+		basket_price
+			.checked_div(&T::SetUSDFixedPrice::get())?
+			.checked_mul(&T::SetterFixedPrice::get())
+	}
+
+	/// Get the price of SETR by the `SetterBasket`, or `SetterFixedPrice` if
+	/// the basket is empty.
+	fn setter_price() -> Option<Price> {
+		if Self::setter_basket().is_empty() {
+			Some(T::SetterFixedPrice::get())
+		} else {
+			Self::get_setter_basket_price()
+		}
+	}
+
+	/// Get the peg price of a stable currency, return `None` if `currency_id`
+	/// is not SETUSD or SETR.
+	fn peg_price(currency_id: CurrencyId) -> Option<Price> {
+		if currency_id == T::GetSetUSDId::get() {
+			Some(T::SetUSDFixedPrice::get())
+		} else if currency_id == T::SetterCurrencyId::get() {
+			Self::setter_price()
+		} else {
+			None
+		}
+	}
+
+	/// Raise or resolve the peg alarm of `currency_id` by how far the further
+	/// of its combined oracle price, as last recorded by `record_price`, and
+	/// its DEX market price deviates from its peg, events are only emitted
+	/// when the severity changes.
+	///
+	/// It is checked when a feed is recorded and on every block for
+	/// `StableCurrencyIds`, so a de-peg on the DEX between feeds is raised too.
+	fn check_peg_deviation(currency_id: CurrencyId) {
+		let (thresholds, peg_price) = match (Self::peg_deviation_thresholds(), Self::peg_price(currency_id)) {
+			(Some(thresholds), Some(peg_price)) if !peg_price.is_zero() => (thresholds, peg_price),
+			_ => return,
+		};
+
+		let recorded_price = Self::price_history(currency_id)
+			.last()
+			.copied()
+			.filter(|_| !Self::is_price_stale(currency_id));
+		// the market price is for 1 basic unit, the peg is for 1 whole token
+		let market_price = Self::get_market_price(currency_id).and_then(|price| {
+			let adjustment_divisor =
+				10u128.checked_pow(18u8.checked_sub(T::CurrencyIdMapping::decimals(currency_id)?)?.into())?;
+			price.into_inner().checked_div(adjustment_divisor).map(Price::from_inner)
+		});
+		let deviation_of = |price: Price| {
+			let difference = if price > peg_price {
+				price.saturating_sub(peg_price)
+			} else {
+				peg_price.saturating_sub(price)
+			};
+			Permill::from_rational(difference.into_inner(), peg_price.into_inner())
+		};
+		let (price, deviation) = match recorded_price
+			.into_iter()
+			.chain(market_price)
+			.map(|price| (price, deviation_of(price)))
+			.max_by_key(|(_, deviation)| *deviation)
+		{
+			Some(furthest) => furthest,
+			None => return,
+		};
+
+		let severity = if deviation >= thresholds.critical {
+			Some(DeviationSeverity::Critical)
+		} else if deviation >= thresholds.warning {
+			Some(DeviationSeverity::Warning)
+		} else if deviation >= thresholds.info {
+			Some(DeviationSeverity::Info)
+		} else {
+			None
+		};
+
+		if severity == Self::peg_deviation_severity(currency_id) {
+			return;
+		}
+		PegDeviationSeverities::<T>::set(currency_id, severity);
+		Self::deposit_event(match severity {
+			Some(DeviationSeverity::Critical) => Event::PegDeviationCritical(currency_id, price, deviation),
+			Some(DeviationSeverity::Warning) => Event::PegDeviationWarning(currency_id, price, deviation),
+			Some(DeviationSeverity::Info) => Event::PegDeviationInfo(currency_id, price, deviation),
+			None => Event::PegDeviationResolved(currency_id, price),
+		});
+	}
+
+	/// Check if the peg of a stable currency is in the critical band, minting
+	/// of `currency_id` should be paused while it is.
+	///
+	/// Note: serp-setmint and serp-treasury are the intended consumers,
+	/// neither is part of this runtime yet.
+	pub fn is_peg_critical(currency_id: CurrencyId) -> bool {
+		Self::peg_deviation_severity(currency_id) == Some(DeviationSeverity::Critical)
+	}
+
+	/// Check if `price` is within the `PriceBounds` of `currency_id`,
+	/// prices of currencies without bounds are always within.
+	pub fn is_within_bounds(currency_id: CurrencyId, price: Price) -> bool {
+		Self::price_bounds(currency_id).map_or(true, |(min, max)| min <= price && price <= max)
+	}
+
+	/// Aggregate the feeds of `currency_id` from `Sources` by `params`,
+	/// return `None` if fewer than `min_feeders` data sources have a feed.
+	fn aggregate_price(currency_id: CurrencyId, params: AggregationParamsOf<T>) -> Option<Price> {
+		let feeds = T::Sources::get_all(&currency_id);
+		let feeders = feeds.iter().filter(|feed| feed.is_some()).count();
+		if feeders.is_zero() || feeders < params.min_feeders as usize {
+			return None;
+		}
+
+		match params.method {
+			AggregationMethod::Median => {
+				let mut prices: Vec<Price> = feeds.into_iter().flatten().collect();
+				prices.sort();
+				let mid = prices.len() / 2;
+				if prices.len() % 2 == 0 {
+					// take the mean of the two middle prices
+					let sum = U256::from(prices[mid - 1].into_inner()) + U256::from(prices[mid].into_inner());
+					TryInto::<u128>::try_into(sum / 2).ok().map(Price::from_inner)
+				} else {
+					Some(prices[mid])
+				}
+			}
+			AggregationMethod::WeightedMean(weights) => {
+				// the aggregated price is none if the weighted sum overflows
+				let (weighted_sum, total_weight) = feeds.iter().zip(weights.iter()).try_fold(
+					(Price::zero(), 0u128),
+					|(weighted_sum, total_weight), (feed, weight)| match feed {
+						Some(price) => {
+							let weighted_price = Price::from_inner(price.into_inner().checked_mul((*weight).into())?);
+							Some((weighted_sum.checked_add(&weighted_price)?, total_weight.checked_add((*weight).into())?))
+						}
+						None => Some((weighted_sum, total_weight)),
+					},
+				)?;
+				weighted_sum.checked_div(&Price::checked_from_integer(total_weight)?)
+			}
+		}
+	}
+
+	/// Whether the oracle price of `currency_id` was last fed more than
+	/// `MaxPriceAge` blocks ago. Prices without a recorded feed are stale, unless
+	/// they were fed in the current block and are yet to be recorded on finalize.
+	pub fn is_price_stale(currency_id: CurrencyId) -> bool {
+		match Self::last_price_update(currency_id) {
+			Some(updated_at) => {
+				frame_system::Pallet::<T>::block_number().saturating_sub(updated_at) > T::MaxPriceAge::get()
+			}
+			None => !FedCurrencyIds::<T>::get().contains(&currency_id),
+		}
+	}
+
+	/// Record the combined feed of `currency_id` from the data source as its
+	/// latest price, or reject it if it's outside the price bounds.
+	fn record_price(currency_id: CurrencyId, now: T::BlockNumber) {
+		let price = match Self::source_price(currency_id) {
+			Some(price) => price,
+			None => return,
+		};
+
+		if Self::is_within_bounds(currency_id, price) {
+			LastPriceUpdate::<T>::insert(currency_id, now);
+			PriceHistory::<T>::mutate(currency_id, |history| {
+				// drop the oldest price to make room for the new one
+				if history.len() >= T::MaxPriceHistory::get() as usize {
+					history.remove(0);
+				}
+				let _ = history.try_push(price);
+			});
+			Self::check_peg_deviation(currency_id);
+		} else {
+			Self::deposit_event(Event::AnomalousPriceRejected(currency_id, price));
+		}
+	}
+
+	/// Add the price observed at the last observation of `currency_id`, weighted
+	/// by the blocks elapsed since, to its cumulative price, and observe its
+	/// price now for the next one.
+	///
+	/// Blocks without an observable price are skipped, the last observed price
+	/// is held over them.
+	fn accumulate_price(currency_id: CurrencyId, now: T::BlockNumber) {
+		if let Some(price) = Self::twap_observed_price(currency_id) {
+			TwapObservations::<T>::mutate(currency_id, |observations| {
+				let cumulative_price = match observations.last() {
+					Some((last_block, _, _)) if *last_block >= now => return,
+					Some((last_block, last_cumulative_price, last_price)) => {
+						let elapsed: u128 = now.saturating_sub(*last_block).saturated_into();
+						last_cumulative_price
+							.saturating_add(last_price.saturating_mul(Price::saturating_from_integer(elapsed)))
+					}
+					None => Zero::zero(),
+				};
+
+				// drop the oldest observation to make room for the new one
+				if observations.len() >= T::MaxTwapObservations::get() as usize {
+					observations.remove(0);
+				}
+				let _ = observations.try_push((now, cumulative_price, price));
+			});
+		}
+	}
+}
+
+impl<T: Config> TwapPriceProvider<CurrencyId, T::BlockNumber> for Pallet<T> {
+	/// Average the cumulative price of `currency_id` from the earliest observation
+	/// inside `window` to the latest one. If the kept observations don't cover the
+	/// whole `window`, the average is taken over the observations available.
+	///
+	/// The TWAP of a stable currency is of its DEX market price, which TES should
+	/// read instead of the spot market price.
+	fn get_twap_price(currency_id: CurrencyId, window: T::BlockNumber) -> Option<Price> {
+		let observations = Self::twap_observations(currency_id);
+		let (latest_block, latest_cumulative_price, _) = *observations.last()?;
+		let window_start = latest_block.saturating_sub(window);
+		let (start_block, start_cumulative_price, _) =
+			*observations.iter().find(|(block, _, _)| *block >= window_start)?;
+
+		let elapsed: u128 = latest_block.saturating_sub(start_block).saturated_into();
+		if elapsed.is_zero() {
+			return None;
+		}
+
+		latest_cumulative_price
+			.checked_sub(&start_cumulative_price)?
+			.checked_div(&Price::saturating_from_integer(elapsed))
+	}
 }
 
 impl<T: Config> LockablePrice<CurrencyId> for Pallet<T> {
@@ -214,6 +950,22 @@ impl<T: Config> LockablePrice<CurrencyId> for Pallet<T> {
 	}
 }
 
+/// Mark the currency of every oracle feed to have it's combined price recorded on finalize,
+/// the feed of a single operator is not used as the price.
+impl<T: Config> OnNewData<T::AccountId, CurrencyId, Price> for Pallet<T> {
+	fn on_new_data(_who: &T::AccountId, currency_id: &CurrencyId, _price: &Price) {
+		FedCurrencyIds::<T>::mutate(|currency_ids| {
+			if !currency_ids.contains(currency_id) {
+				currency_ids.push(*currency_id);
+				frame_system::Pallet::<T>::register_extra_weight_unchecked(
+					T::WeightInfo::on_finalize(1).saturating_sub(T::WeightInfo::on_finalize(0)),
+					DispatchClass::Mandatory,
+				);
+			}
+		});
+	}
+}
+
 /// PriceProvider that always provider real-time prices from oracle
 pub struct RealTimePriceProvider<T>(PhantomData<T>);
 impl<T: Config> PriceProvider<CurrencyId> for RealTimePriceProvider<T> {
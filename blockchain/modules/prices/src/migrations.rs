@@ -0,0 +1,107 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations for the prices module.
+
+use super::*;
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade};
+
+/// Move every per-currency entry of the prices module from the old to the
+/// new currency id of each pair in `Renames`, e.g. when a token is renamed,
+/// and replace the old ids in `SetterBasket`.
+///
+/// Runs only while the on-chain storage version is 0 and bumps it to 1, so
+/// it is a no-op if it is left in the runtime's migrations after an upgrade.
+///
+/// The new currency ids must not have entries of their own yet; with
+/// `try-runtime`, `pre_upgrade` checks that and `post_upgrade` checks that
+/// no entry is left under an old currency id.
+pub struct RenameCurrencyIds<T, Renames>(PhantomData<(T, Renames)>);
+
+impl<T: Config, Renames: Get<Vec<(CurrencyId, CurrencyId)>>> OnRuntimeUpgrade for RenameCurrencyIds<T, Renames> {
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let renames = Renames::get();
+		for (old, new) in renames.iter() {
+			LockedPrice::<T>::swap(old, new);
+			LastPriceUpdate::<T>::swap(old, new);
+			AggregationConfigs::<T>::swap(old, new);
+			PriceBounds::<T>::swap(old, new);
+			PriceHistory::<T>::swap(old, new);
+			TwapObservations::<T>::swap(old, new);
+			PegDeviationSeverities::<T>::swap(old, new);
+		}
+		SetterBasket::<T>::mutate(|basket| {
+			for (currency_id, _) in basket.iter_mut() {
+				if let Some((_, new)) = renames.iter().find(|(old, _)| old == currency_id) {
+					*currency_id = *new;
+				}
+			}
+		});
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		let swaps = renames.len().saturating_mul(7) as Weight;
+		T::DbWeight::get().reads_writes(swaps.saturating_mul(2).saturating_add(2), swaps.saturating_mul(2).saturating_add(2))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<(), &'static str> {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return Ok(());
+		}
+		for (_, new) in Renames::get().iter() {
+			ensure!(!Self::has_entries(new), "prices: renamed currency id already has entries");
+		}
+		Ok(())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade() -> Result<(), &'static str> {
+		ensure!(
+			Pallet::<T>::on_chain_storage_version() == 1,
+			"prices: storage version was not bumped"
+		);
+		let renames = Renames::get();
+		for (old, _) in renames.iter() {
+			ensure!(!Self::has_entries(old), "prices: entries left under a renamed currency id");
+			ensure!(
+				!SetterBasket::<T>::get().iter().any(|(currency_id, _)| currency_id == old),
+				"prices: renamed currency id left in the setter basket"
+			);
+		}
+		Ok(())
+	}
+}
+
+#[cfg(feature = "try-runtime")]
+impl<T: Config, Renames> RenameCurrencyIds<T, Renames> {
+	fn has_entries(currency_id: &CurrencyId) -> bool {
+		LockedPrice::<T>::contains_key(currency_id)
+			|| LastPriceUpdate::<T>::contains_key(currency_id)
+			|| AggregationConfigs::<T>::contains_key(currency_id)
+			|| PriceBounds::<T>::contains_key(currency_id)
+			|| PriceHistory::<T>::contains_key(currency_id)
+			|| TwapObservations::<T>::contains_key(currency_id)
+			|| PegDeviationSeverities::<T>::contains_key(currency_id)
+	}
+}
@@ -26,15 +26,15 @@ use super::*;
 use frame_support::{construct_runtime, ord_parameter_types, parameter_types};
 use frame_system::EnsureSignedBy;
 use orml_traits::{parameter_type_with_key, DataFeeder};
-use primitives::{currency::DexShare, Amount, TokenSymbol};
+use primitives::{currency::DexShare, evm::EvmAddress, Amount, TokenSymbol};
 use sp_core::{H160, H256};
 use sp_runtime::{
 	testing::Header,
 	traits::{IdentityLookup, One as OneT, Zero},
 	DispatchError, FixedPointNumber,
 };
-use sp_std::cell::RefCell;
-use support::{mocks::MockCurrencyIdMapping, SwapLimit};
+use sp_std::{cell::RefCell, collections::btree_map::BTreeMap};
+use support::{mocks::MockCurrencyIdMapping, SwapLimit, SwapPath};
 
 pub type AccountId = u128;
 pub type BlockNumber = u64;
@@ -44,6 +44,10 @@ pub const SETUSD: CurrencyId = CurrencyId::Token(TokenSymbol::SETUSD);
 pub const SETR: CurrencyId = CurrencyId::Token(TokenSymbol::SETR);
 pub const SERP: CurrencyId = CurrencyId::Token(TokenSymbol::SERP);
 pub const DNAR: CurrencyId = CurrencyId::Token(TokenSymbol::DNAR);
+pub const HELP: CurrencyId = CurrencyId::Token(TokenSymbol::HELP);
+pub const ERC20_USDC: CurrencyId = CurrencyId::Erc20(H160([6u8; 20]));
+pub const ERC20_WBTC: CurrencyId = CurrencyId::Erc20(H160([8u8; 20]));
+pub const ERC20_WETH: CurrencyId = CurrencyId::Erc20(H160([18u8; 20]));
 pub const LP_SETUSD_DNAR: CurrencyId =
 	CurrencyId::DexShare(DexShare::Token(TokenSymbol::SETUSD), DexShare::Token(TokenSymbol::DNAR));
 
@@ -83,15 +87,37 @@ impl frame_system::Config for Runtime {
 
 thread_local! {
 	static CHANGED: RefCell<bool> = RefCell::new(false);
+	static FEEDS: RefCell<BTreeMap<CurrencyId, Option<Price>>> = RefCell::new(BTreeMap::new());
+	static SECOND_FEEDS: RefCell<BTreeMap<CurrencyId, Option<Price>>> = RefCell::new(BTreeMap::new());
+	static MARKET_SERP_RESERVE: RefCell<Balance> = RefCell::new(1_000_000);
+}
+
+/// Override the SERP reserve of the SERP-SETUSD pool returned by `MockDEX`.
+pub fn mock_market_serp_reserve(reserve: Balance) {
+	MARKET_SERP_RESERVE.with(|v| *v.borrow_mut() = reserve);
 }
 
 pub fn mock_oracle_update() {
 	CHANGED.with(|v| *v.borrow_mut() = true)
 }
 
+/// Override the feed of `currency_id` returned by `MockDataProvider`.
+pub fn mock_oracle_feed(currency_id: CurrencyId, price: Option<Price>) {
+	FEEDS.with(|v| v.borrow_mut().insert(currency_id, price));
+}
+
+/// Override the feed of `currency_id` returned by `SecondMockDataProvider`.
+pub fn mock_second_oracle_feed(currency_id: CurrencyId, price: Option<Price>) {
+	SECOND_FEEDS.with(|v| v.borrow_mut().insert(currency_id, price));
+}
+
 pub struct MockDataProvider;
 impl DataProvider<CurrencyId, Price> for MockDataProvider {
 	fn get(currency_id: &CurrencyId) -> Option<Price> {
+		if let Some(price) = FEEDS.with(|v| v.borrow().get(currency_id).cloned()) {
+			return price;
+		}
+
 		if CHANGED.with(|v| *v.borrow_mut()) {
 			match *currency_id {
 				SETUSD => None,
@@ -106,6 +132,8 @@ impl DataProvider<CurrencyId, Price> for MockDataProvider {
 				SERP => Some(Price::saturating_from_integer(50000)),
 				DNAR => Some(Price::saturating_from_integer(100)),
 				SETM => Some(Price::zero()),
+				ERC20_USDC => Some(Price::one()),
+				ERC20_WBTC => Some(Price::saturating_from_integer(50000)),
 				_ => None,
 			}
 		}
@@ -118,11 +146,81 @@ impl DataFeeder<CurrencyId, Price, AccountId> for MockDataProvider {
 	}
 }
 
+pub struct SecondMockDataProvider;
+impl DataProvider<CurrencyId, Price> for SecondMockDataProvider {
+	fn get(currency_id: &CurrencyId) -> Option<Price> {
+		if let Some(price) = SECOND_FEEDS.with(|v| v.borrow().get(currency_id).cloned()) {
+			return price;
+		}
+
+		match *currency_id {
+			SETUSD => Some(Price::one()),
+			SERP => Some(Price::saturating_from_integer(51000)),
+			_ => None,
+		}
+	}
+}
+
+pub struct ThirdMockDataProvider;
+impl DataProvider<CurrencyId, Price> for ThirdMockDataProvider {
+	fn get(currency_id: &CurrencyId) -> Option<Price> {
+		match *currency_id {
+			SETUSD => Some(Price::saturating_from_rational(101, 100)),
+			SERP => Some(Price::saturating_from_integer(48000)),
+			DNAR => Some(Price::saturating_from_integer(110)),
+			_ => None,
+		}
+	}
+}
+
+/// Maps the mock ERC20 tokens to their on-contract decimals.
+pub struct MockErc20CurrencyIdMapping;
+impl CurrencyIdMapping for MockErc20CurrencyIdMapping {
+	fn set_erc20_mapping(address: EvmAddress) -> DispatchResult {
+		MockCurrencyIdMapping::set_erc20_mapping(address)
+	}
+
+	fn get_evm_address(currency_id: u32) -> Option<EvmAddress> {
+		MockCurrencyIdMapping::get_evm_address(currency_id)
+	}
+
+	fn name(currency_id: CurrencyId) -> Option<Vec<u8>> {
+		MockCurrencyIdMapping::name(currency_id)
+	}
+
+	fn symbol(currency_id: CurrencyId) -> Option<Vec<u8>> {
+		MockCurrencyIdMapping::symbol(currency_id)
+	}
+
+	fn decimals(currency_id: CurrencyId) -> Option<u8> {
+		match currency_id {
+			ERC20_USDC => Some(6),
+			ERC20_WBTC => Some(8),
+			ERC20_WETH => Some(18),
+			_ => MockCurrencyIdMapping::decimals(currency_id),
+		}
+	}
+
+	fn encode_evm_address(v: CurrencyId) -> Option<EvmAddress> {
+		MockCurrencyIdMapping::encode_evm_address(v)
+	}
+
+	fn decode_evm_address(v: EvmAddress) -> Option<CurrencyId> {
+		MockCurrencyIdMapping::decode_evm_address(v)
+	}
+}
+
 pub struct MockDEX;
 impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 	fn get_liquidity_pool(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> (Balance, Balance) {
 		match (currency_id_a, currency_id_b) {
 			(SETUSD, DNAR) => (10000, 200),
+			(SETUSD, HELP) => (2_000_000, 1_000_000),
+			(SERP, SETUSD) => (MARKET_SERP_RESERVE.with(|v| *v.borrow()), 50_000_000_000),
+			(SETUSD, ERC20_WETH) => (
+				3_000_000 * 10u128.pow(MockCurrencyIdMapping::decimals(SETUSD).unwrap().into()),
+				1_000 * 10u128.pow(18),
+			),
 			_ => (0, 0),
 		}
 	}
@@ -131,7 +229,7 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 		unimplemented!()
 	}
 
-	fn get_swap_amount(_: &[CurrencyId], _: SwapLimit<Balance>) -> Option<(Balance, Balance)> {
+	fn get_swap_amount(_: &SwapPath<CurrencyId>, _: SwapLimit<Balance>) -> Option<(Balance, Balance)> {
 		unimplemented!()
 	}
 
@@ -140,13 +238,13 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 		_: CurrencyId,
 		_: SwapLimit<Balance>,
 		_: Vec<Vec<CurrencyId>>,
-	) -> Option<Vec<CurrencyId>> {
+	) -> Option<SwapPath<CurrencyId>> {
 		unimplemented!()
 	}
 
 	fn swap_with_specific_path(
 		_: &AccountId,
-		_: &[CurrencyId],
+		_: &SwapPath<CurrencyId>,
 		_: SwapLimit<Balance>,
 	) -> sp_std::result::Result<(Balance, Balance), DispatchError> {
 		unimplemented!()
@@ -154,7 +252,7 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 
 	fn buyback_swap_with_specific_path(
 		_: &AccountId,
-		_: &[CurrencyId],
+		_: &SwapPath<CurrencyId>,
 		_: SwapLimit<Balance>,
 	) -> sp_std::result::Result<(Balance, Balance), DispatchError> {
 		unimplemented!()
@@ -162,7 +260,7 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 
 	fn swap_with_exact_target(
 		_who: &AccountId,
-		_path: &[CurrencyId],
+		_path: &SwapPath<CurrencyId>,
 		_exact_target_amount: Balance,
 		_max_supply_amount: Balance,
 	) -> DispatchResult {
@@ -219,19 +317,39 @@ parameter_types! {
 	pub const SetterCurrencyId: CurrencyId = SETR;
 	pub SetUSDFixedPrice: Price = Price::one();
 	pub SetterFixedPrice: Price = Price::saturating_from_rational(1, 4); // $0.25
+	pub const MaxSetterBasketLength: u32 = 3;
+	pub const MaxFeeders: u32 = 3;
+	pub const MinimumDexLiquidity: Balance = 1_000_000;
+	pub TwapCurrencyIds: Vec<CurrencyId> = vec![SERP, DNAR];
+	pub StableCurrencyIds: Vec<CurrencyId> = vec![SETUSD, SETR];
+	pub const GetMarketReferenceCurrencyId: CurrencyId = SERP;
+	pub const MaxTwapObservations: u32 = 3;
+	pub const MaxPriceAge: BlockNumber = 10;
+	pub const MaxPriceHistory: u32 = 3;
 }
 
 impl Config for Runtime {
 	type Event = Event;
 	type Source = MockDataProvider;
+	type Sources = (MockDataProvider, SecondMockDataProvider, ThirdMockDataProvider);
+	type MaxFeeders = MaxFeeders;
 	type GetSetUSDId = GetSetUSDId;
 	type SetterCurrencyId = SetterCurrencyId;
 	type SetUSDFixedPrice = SetUSDFixedPrice;
 	type SetterFixedPrice = SetterFixedPrice;
+	type MaxSetterBasketLength = MaxSetterBasketLength;
 	type LockOrigin = EnsureSignedBy<One, AccountId>;
+	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
 	type DEX = MockDEX;
+	type MinimumDexLiquidity = MinimumDexLiquidity;
 	type Currency = Tokens;
-	type CurrencyIdMapping = MockCurrencyIdMapping;
+	type CurrencyIdMapping = MockErc20CurrencyIdMapping;
+	type TwapCurrencyIds = TwapCurrencyIds;
+	type StableCurrencyIds = StableCurrencyIds;
+	type GetMarketReferenceCurrencyId = GetMarketReferenceCurrencyId;
+	type MaxTwapObservations = MaxTwapObservations;
+	type MaxPriceAge = MaxPriceAge;
+	type MaxPriceHistory = MaxPriceHistory;
 	type WeightInfo = ();
 }
 
@@ -264,6 +382,13 @@ impl ExtBuilder {
 			.build_storage::<Runtime>()
 			.unwrap();
 
-		t.into()
+		let mut ext = sp_io::TestExternalities::new(t);
+		// the feeds of `MockDataProvider` are recorded at genesis
+		ext.execute_with(|| {
+			for currency_id in &[SETUSD, SETM, SERP, DNAR, ERC20_USDC, ERC20_WBTC] {
+				LastPriceUpdate::<Runtime>::insert(currency_id, 0);
+			}
+		});
+		ext
 	}
 }
@@ -30,6 +30,13 @@ use sp_runtime::{
 	FixedPointNumber,
 };
 
+/// Feed `price` as the combined price of `currency_id` and record it at `block`.
+fn feed_price(currency_id: CurrencyId, price: Price, block: BlockNumber) {
+	mock_oracle_feed(currency_id, Some(price));
+	PricesModule::on_new_data(&1, &currency_id, &price);
+	PricesModule::on_finalize(block);
+}
+
 #[test]
 fn lp_token_fair_price_works() {
 	let lp_token_fair_price_0 = lp_token_fair_price(
@@ -440,3 +447,909 @@ fn price_providers_work() {
 		assert_eq!(LockedPriceProvider::<Runtime>::get_relative_price(SERP, SETR), None);
 	});
 }
+
+#[test]
+fn accumulate_price_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let serp_price = PricesModule::access_price(SERP).unwrap();
+		let dnar_price = PricesModule::access_price(DNAR).unwrap();
+		assert_eq!(PricesModule::twap_observations(SERP), vec![]);
+
+		PricesModule::on_initialize(1);
+		assert_eq!(PricesModule::twap_observations(SERP), vec![(1, Price::zero(), serp_price)]);
+		assert_eq!(PricesModule::twap_observations(DNAR), vec![(1, Price::zero(), dnar_price)]);
+		assert_eq!(PricesModule::twap_observations(HELP), vec![]);
+
+		// accumulate the price weighted by the blocks elapsed
+		PricesModule::on_initialize(3);
+		assert_eq!(
+			PricesModule::twap_observations(SERP),
+			vec![
+				(1, Price::zero(), serp_price),
+				(3, serp_price.saturating_mul(Price::saturating_from_integer(2)), serp_price)
+			]
+		);
+		assert_eq!(
+			PricesModule::twap_observations(DNAR),
+			vec![
+				(1, Price::zero(), dnar_price),
+				(3, dnar_price.saturating_mul(Price::saturating_from_integer(2)), dnar_price)
+			]
+		);
+
+		// the same block is only accumulated once
+		PricesModule::on_initialize(3);
+		assert_eq!(PricesModule::twap_observations(SERP).len(), 2);
+
+		// the blocks elapsed are weighted by the price observed before them
+		mock_oracle_update();
+		let serp_price_1 = PricesModule::access_price(SERP).unwrap();
+		PricesModule::on_initialize(4);
+		PricesModule::on_initialize(5);
+		assert_eq!(
+			PricesModule::twap_observations(SERP),
+			vec![
+				(3, serp_price.saturating_mul(Price::saturating_from_integer(2)), serp_price),
+				(4, serp_price.saturating_mul(Price::saturating_from_integer(3)), serp_price_1),
+				(
+					5,
+					serp_price.saturating_mul(Price::saturating_from_integer(3)).saturating_add(serp_price_1),
+					serp_price_1
+				),
+			]
+		);
+	});
+}
+
+#[test]
+fn get_twap_price_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let serp_price_0 = PricesModule::access_price(SERP).unwrap();
+		assert_eq!(PricesModule::get_twap_price(SERP, 10), None);
+
+		// a single observation doesn't span any block
+		PricesModule::on_initialize(1);
+		assert_eq!(PricesModule::get_twap_price(SERP, 10), None);
+
+		PricesModule::on_initialize(3);
+		assert_eq!(PricesModule::get_twap_price(SERP, 10), Some(serp_price_0));
+
+		mock_oracle_update();
+		let serp_price_1 = PricesModule::access_price(SERP).unwrap();
+		assert_ne!(serp_price_0, serp_price_1);
+
+		// the price pushed at block 5 only counts from block 5 on
+		PricesModule::on_initialize(5);
+		assert_eq!(PricesModule::get_twap_price(SERP, 10), Some(serp_price_0));
+
+		PricesModule::on_initialize(7);
+		assert_eq!(
+			PricesModule::get_twap_price(SERP, 10),
+			Some(Price::from_inner(
+				(serp_price_0.into_inner() + serp_price_1.into_inner()) / 2
+			))
+		);
+		assert_eq!(PricesModule::get_twap_price(SERP, 4), PricesModule::get_twap_price(SERP, 10));
+		assert_eq!(PricesModule::get_twap_price(SERP, 2), Some(serp_price_1));
+		assert_eq!(PricesModule::get_twap_price(SERP, 0), None);
+
+		// currencies not in `TwapCurrencyIds` or `StableCurrencyIds` have no TWAP
+		assert_eq!(PricesModule::get_twap_price(HELP, 10), None);
+	});
+}
+
+#[test]
+fn get_market_price_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let serp_price = PricesModule::access_price(SERP).unwrap();
+		assert_eq!(
+			PricesModule::get_market_price(SETUSD),
+			Some(serp_price.saturating_mul(Price::saturating_from_rational(1_000_000, 50_000_000_000u128)))
+		);
+
+		// the stable currency reserve is below `MinimumDexLiquidity`
+		assert_eq!(PricesModule::get_market_price(SETR), None);
+	});
+}
+
+#[test]
+fn stable_currency_twap_is_of_market_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		let market_price_0 = PricesModule::get_market_price(SETUSD).unwrap();
+		// the fixed price of SETUSD is not observed
+		assert_ne!(market_price_0, PricesModule::access_price(SETUSD).unwrap());
+
+		PricesModule::on_initialize(1);
+		PricesModule::on_initialize(3);
+		assert_eq!(PricesModule::get_twap_price(SETUSD, 10), Some(market_price_0));
+
+		// a DEX price push within a block doesn't move the TWAP
+		mock_market_serp_reserve(2_000_000);
+		let market_price_1 = PricesModule::get_market_price(SETUSD).unwrap();
+		assert_eq!(market_price_1, market_price_0.saturating_mul(Price::saturating_from_integer(2)));
+		PricesModule::on_initialize(4);
+		assert_eq!(PricesModule::get_twap_price(SETUSD, 10), Some(market_price_0));
+
+		mock_market_serp_reserve(1_000_000);
+		PricesModule::on_initialize(5);
+		assert_eq!(
+			PricesModule::get_twap_price(SETUSD, 10),
+			Some(Price::from_inner(
+				(market_price_0.into_inner() + market_price_1.into_inner()) / 2
+			))
+		);
+
+		// no market price without enough liquidity
+		assert_eq!(PricesModule::get_twap_price(SETR, 10), None);
+	});
+}
+
+#[test]
+fn stale_price_is_rejected() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		// prices without a recorded feed are stale
+		LastPriceUpdate::<Runtime>::remove(SERP);
+		assert!(PricesModule::is_price_stale(SERP));
+		assert_eq!(PricesModule::access_price(SERP), None);
+
+		// a feed is fresh in the block it is fed in, and recorded on finalize
+		PricesModule::on_new_data(&1, &SERP, &Price::saturating_from_integer(50000u128));
+		assert_eq!(PricesModule::last_price_update(SERP), None);
+		assert!(!PricesModule::is_price_stale(SERP));
+		assert_eq!(
+			PricesModule::access_price(SERP),
+			Some(Price::saturating_from_integer(50000u128))
+		);
+		PricesModule::on_finalize(1);
+		assert_eq!(PricesModule::last_price_update(SERP), Some(1));
+
+		System::set_block_number(11);
+		assert!(!PricesModule::is_price_stale(SERP));
+		assert_eq!(
+			PricesModule::access_price(SERP),
+			Some(Price::saturating_from_integer(50000u128))
+		);
+
+		System::set_block_number(12);
+		assert!(PricesModule::is_price_stale(SERP));
+		assert_eq!(PricesModule::access_price(SERP), None);
+		assert_eq!(RealTimePriceProvider::<Runtime>::get_price(SERP), None);
+		assert_noop!(
+			PricesModule::lock_price(Origin::signed(1), SERP),
+			Error::<Runtime>::AccessPriceFailed
+		);
+
+		// fixed prices never become stale
+		PricesModule::on_new_data(&1, &SETUSD, &Price::saturating_from_integer(1u128));
+		PricesModule::on_finalize(12);
+		System::set_block_number(100);
+		assert_eq!(
+			PricesModule::access_price(SETUSD),
+			Some(Price::saturating_from_integer(1u128))
+		);
+
+		// a new feed makes the price accessible again
+		PricesModule::on_new_data(&1, &SERP, &Price::saturating_from_integer(50000u128));
+		PricesModule::on_finalize(100);
+		assert_eq!(PricesModule::last_price_update(SERP), Some(100));
+		assert_eq!(
+			PricesModule::access_price(SERP),
+			Some(Price::saturating_from_integer(50000u128))
+		);
+	});
+}
+
+#[test]
+fn set_aggregation_params_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		let median = AggregationParams {
+			method: AggregationMethod::Median,
+			min_feeders: 2,
+		};
+		assert_noop!(
+			PricesModule::set_aggregation_params(Origin::signed(5), SERP, Some(median.clone())),
+			BadOrigin
+		);
+		assert_noop!(
+			PricesModule::set_aggregation_params(
+				Origin::signed(1),
+				SERP,
+				Some(AggregationParams {
+					method: AggregationMethod::Median,
+					min_feeders: 4,
+				})
+			),
+			Error::<Runtime>::InvalidAggregationParams
+		);
+		assert_noop!(
+			PricesModule::set_aggregation_params(
+				Origin::signed(1),
+				SERP,
+				Some(AggregationParams {
+					method: AggregationMethod::WeightedMean(vec![1, 2].try_into().unwrap()),
+					min_feeders: 1,
+				})
+			),
+			Error::<Runtime>::InvalidAggregationParams
+		);
+
+		assert_ok!(PricesModule::set_aggregation_params(
+			Origin::signed(1),
+			SERP,
+			Some(median.clone())
+		));
+		System::assert_last_event(Event::PricesModule(crate::Event::AggregationParamsUpdated(
+			SERP,
+			Some(median.clone()),
+		)));
+		assert_eq!(PricesModule::aggregation_params(SERP), Some(median));
+
+		assert_ok!(PricesModule::set_aggregation_params(Origin::signed(1), SERP, None));
+		System::assert_last_event(Event::PricesModule(crate::Event::AggregationParamsUpdated(SERP, None)));
+		assert_eq!(PricesModule::aggregation_params(SERP), None);
+	});
+}
+
+#[test]
+fn aggregated_price_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		// median of 50000, 51000 and 48000
+		assert_ok!(PricesModule::set_aggregation_params(
+			Origin::signed(1),
+			SERP,
+			Some(AggregationParams {
+				method: AggregationMethod::Median,
+				min_feeders: 2,
+			})
+		));
+		assert_eq!(
+			PricesModule::access_price(SERP),
+			Some(Price::saturating_from_integer(50000u128))
+		);
+
+		// median of 100 and 110
+		assert_ok!(PricesModule::set_aggregation_params(
+			Origin::signed(1),
+			DNAR,
+			Some(AggregationParams {
+				method: AggregationMethod::Median,
+				min_feeders: 2,
+			})
+		));
+		assert_eq!(
+			PricesModule::access_price(DNAR),
+			Some(Price::saturating_from_integer(105u128))
+		);
+
+		// (50000 * 1 + 51000 * 1 + 48000 * 2) / 4
+		assert_ok!(PricesModule::set_aggregation_params(
+			Origin::signed(1),
+			SERP,
+			Some(AggregationParams {
+				method: AggregationMethod::WeightedMean(vec![1, 1, 2].try_into().unwrap()),
+				min_feeders: 3,
+			})
+		));
+		assert_eq!(
+			PricesModule::access_price(SERP),
+			Some(Price::saturating_from_integer(49250u128))
+		);
+
+		// only two feeders for DNAR, quorum not reached
+		assert_ok!(PricesModule::set_aggregation_params(
+			Origin::signed(1),
+			DNAR,
+			Some(AggregationParams {
+				method: AggregationMethod::WeightedMean(vec![1, 1, 2].try_into().unwrap()),
+				min_feeders: 3,
+			})
+		));
+		assert_eq!(PricesModule::access_price(DNAR), None);
+	});
+}
+
+#[test]
+fn aggregated_price_does_not_lose_precision_or_overflow() {
+	ExtBuilder::default().build().execute_with(|| {
+		let median = AggregationParams {
+			method: AggregationMethod::Median,
+			min_feeders: 2,
+		};
+		let weighted_mean = AggregationParams {
+			method: AggregationMethod::WeightedMean(vec![1, 1, 1].try_into().unwrap()),
+			min_feeders: 2,
+		};
+
+		// the mean of the two middle prices is rounded down once
+		mock_oracle_feed(HELP, Some(Price::from_inner(3)));
+		mock_second_oracle_feed(HELP, Some(Price::from_inner(5)));
+		assert_eq!(PricesModule::aggregate_price(HELP, median.clone()), Some(Price::from_inner(4)));
+
+		mock_oracle_feed(HELP, Some(Price::from_inner(u128::MAX)));
+		mock_second_oracle_feed(HELP, Some(Price::from_inner(u128::MAX)));
+		assert_eq!(PricesModule::aggregate_price(HELP, median), Some(Price::from_inner(u128::MAX)));
+
+		// the weighted sum overflows
+		assert_eq!(PricesModule::aggregate_price(HELP, weighted_mean.clone()), None);
+
+		mock_second_oracle_feed(HELP, Some(Price::from_inner(1)));
+		assert_eq!(PricesModule::aggregate_price(HELP, weighted_mean), None);
+	});
+}
+
+#[test]
+fn set_price_bounds_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		let bounds = (Price::saturating_from_integer(10000u128), Price::saturating_from_integer(60000u128));
+		assert_noop!(
+			PricesModule::set_price_bounds(Origin::signed(5), SERP, Some(bounds)),
+			BadOrigin
+		);
+		assert_noop!(
+			PricesModule::set_price_bounds(Origin::signed(1), SERP, Some((bounds.1, bounds.0))),
+			Error::<Runtime>::InvalidPriceBounds
+		);
+
+		assert_ok!(PricesModule::set_price_bounds(Origin::signed(1), SERP, Some(bounds)));
+		System::assert_last_event(Event::PricesModule(crate::Event::PriceBoundsUpdated(SERP, Some(bounds))));
+		assert_eq!(PricesModule::price_bounds(SERP), Some(bounds));
+
+		assert_ok!(PricesModule::set_price_bounds(Origin::signed(1), SERP, None));
+		System::assert_last_event(Event::PricesModule(crate::Event::PriceBoundsUpdated(SERP, None)));
+		assert_eq!(PricesModule::price_bounds(SERP), None);
+	});
+}
+
+#[test]
+fn anomalous_price_is_rejected() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(PricesModule::set_price_bounds(
+			Origin::signed(1),
+			SERP,
+			Some((Price::saturating_from_integer(10000u128), Price::saturating_from_integer(60000u128)))
+		));
+		assert_ok!(PricesModule::set_price_bounds(
+			Origin::signed(1),
+			DNAR,
+			Some((Price::saturating_from_integer(1u128), Price::saturating_from_integer(50u128)))
+		));
+
+		// within the bounds
+		assert_eq!(
+			PricesModule::access_price(SERP),
+			Some(Price::saturating_from_integer(50000u128))
+		);
+		PricesModule::on_new_data(&1, &SERP, &Price::saturating_from_integer(50000u128));
+		PricesModule::on_finalize(1);
+		assert_eq!(PricesModule::last_price_update(SERP), Some(1));
+
+		// outside the bounds
+		assert_eq!(PricesModule::access_price(DNAR), None);
+		assert_noop!(
+			PricesModule::lock_price(Origin::signed(1), DNAR),
+			Error::<Runtime>::AccessPriceFailed
+		);
+		PricesModule::on_new_data(&1, &DNAR, &Price::saturating_from_integer(100u128));
+		PricesModule::on_finalize(1);
+		System::assert_last_event(Event::PricesModule(crate::Event::AnomalousPriceRejected(
+			DNAR,
+			Price::saturating_from_integer(100u128),
+		)));
+		assert_eq!(PricesModule::last_price_update(DNAR), Some(0));
+	});
+}
+
+#[test]
+fn set_peg_deviation_thresholds_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		let thresholds = DeviationThresholds {
+			info: Permill::from_percent(1),
+			warning: Permill::from_percent(3),
+			critical: Permill::from_percent(10),
+		};
+		assert_noop!(
+			PricesModule::set_peg_deviation_thresholds(Origin::signed(5), Some(thresholds)),
+			BadOrigin
+		);
+		assert_noop!(
+			PricesModule::set_peg_deviation_thresholds(
+				Origin::signed(1),
+				Some(DeviationThresholds {
+					warning: Permill::from_percent(20),
+					..thresholds
+				})
+			),
+			Error::<Runtime>::InvalidDeviationThresholds
+		);
+
+		assert_ok!(PricesModule::set_peg_deviation_thresholds(
+			Origin::signed(1),
+			Some(thresholds)
+		));
+		System::assert_last_event(Event::PricesModule(crate::Event::PegDeviationThresholdsUpdated(Some(
+			thresholds,
+		))));
+		assert_eq!(PricesModule::peg_deviation_thresholds(), Some(thresholds));
+
+		assert_ok!(PricesModule::set_peg_deviation_thresholds(Origin::signed(1), None));
+		System::assert_last_event(Event::PricesModule(crate::Event::PegDeviationThresholdsUpdated(None)));
+		assert_eq!(PricesModule::peg_deviation_thresholds(), None);
+	});
+}
+
+#[test]
+fn peg_deviation_alarms_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		// no alarms without thresholds
+		feed_price(SETUSD, Price::saturating_from_rational(80, 100), 1);
+		assert_eq!(PricesModule::peg_deviation_severity(SETUSD), None);
+
+		assert_ok!(PricesModule::set_peg_deviation_thresholds(
+			Origin::signed(1),
+			Some(DeviationThresholds {
+				info: Permill::from_percent(1),
+				warning: Permill::from_percent(3),
+				critical: Permill::from_percent(10),
+			})
+		));
+
+		feed_price(SETUSD, Price::saturating_from_rational(98, 100), 2);
+		System::assert_last_event(Event::PricesModule(crate::Event::PegDeviationInfo(
+			SETUSD,
+			Price::saturating_from_rational(98, 100),
+			Permill::from_percent(2),
+		)));
+		assert_eq!(PricesModule::peg_deviation_severity(SETUSD), Some(DeviationSeverity::Info));
+
+		feed_price(SETUSD, Price::saturating_from_rational(105, 100), 3);
+		System::assert_last_event(Event::PricesModule(crate::Event::PegDeviationWarning(
+			SETUSD,
+			Price::saturating_from_rational(105, 100),
+			Permill::from_percent(5),
+		)));
+		assert!(!PricesModule::is_peg_critical(SETUSD));
+
+		// SETR is pegged to its fixed price while the basket is empty
+		feed_price(SETR, Price::saturating_from_rational(30, 100), 4);
+		System::assert_last_event(Event::PricesModule(crate::Event::PegDeviationCritical(
+			SETR,
+			Price::saturating_from_rational(30, 100),
+			Permill::from_percent(20),
+		)));
+		assert!(PricesModule::is_peg_critical(SETR));
+
+		// the same severity is not raised again
+		let events = System::events().len();
+		feed_price(SETR, Price::saturating_from_rational(31, 100), 5);
+		assert_eq!(System::events().len(), events);
+
+		feed_price(SETR, Price::saturating_from_rational(1, 4), 6);
+		System::assert_last_event(Event::PricesModule(crate::Event::PegDeviationResolved(
+			SETR,
+			Price::saturating_from_rational(1, 4),
+		)));
+		assert_eq!(PricesModule::peg_deviation_severity(SETR), None);
+
+		// other currencies have no peg
+		feed_price(SERP, Price::saturating_from_integer(1u128), 7);
+		assert_eq!(PricesModule::peg_deviation_severity(SERP), None);
+	});
+}
+
+#[test]
+fn peg_deviation_alarms_use_market_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(PricesModule::set_peg_deviation_thresholds(
+			Origin::signed(1),
+			Some(DeviationThresholds {
+				info: Permill::from_percent(1),
+				warning: Permill::from_percent(3),
+				critical: Permill::from_percent(10),
+			})
+		));
+
+		// SETUSD is at its peg on the DEX
+		PricesModule::on_initialize(1);
+		assert_eq!(PricesModule::peg_deviation_severity(SETUSD), None);
+
+		// de-peg on the DEX between feeds
+		mock_market_serp_reserve(800_000);
+		PricesModule::on_initialize(2);
+		System::assert_last_event(Event::PricesModule(crate::Event::PegDeviationCritical(
+			SETUSD,
+			Price::saturating_from_rational(80, 100),
+			Permill::from_percent(20),
+		)));
+		assert!(PricesModule::is_peg_critical(SETUSD));
+
+		// a feed at the peg doesn't resolve the alarm while the market price is off it
+		let events = System::events().len();
+		feed_price(SETUSD, Price::one(), 3);
+		assert_eq!(System::events().len(), events);
+		assert!(PricesModule::is_peg_critical(SETUSD));
+
+		// the further of the fed and market prices raises the alarm
+		mock_market_serp_reserve(1_000_000);
+		feed_price(SETUSD, Price::saturating_from_rational(105, 100), 4);
+		System::assert_last_event(Event::PricesModule(crate::Event::PegDeviationWarning(
+			SETUSD,
+			Price::saturating_from_rational(105, 100),
+			Permill::from_percent(5),
+		)));
+
+		feed_price(SETUSD, Price::one(), 5);
+		System::assert_last_event(Event::PricesModule(crate::Event::PegDeviationResolved(
+			SETUSD,
+			Price::one(),
+		)));
+		assert_eq!(PricesModule::peg_deviation_severity(SETUSD), None);
+	});
+}
+
+#[test]
+fn peg_deviation_alarms_use_combined_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(PricesModule::set_peg_deviation_thresholds(
+			Origin::signed(1),
+			Some(DeviationThresholds {
+				info: Permill::from_percent(1),
+				warning: Permill::from_percent(3),
+				critical: Permill::from_percent(10),
+			})
+		));
+		assert_ok!(PricesModule::set_aggregation_params(
+			Origin::signed(1),
+			SETUSD,
+			Some(AggregationParams {
+				method: AggregationMethod::Median,
+				min_feeders: 2,
+			})
+		));
+
+		// median of 0.8, 1 and 1.01, the outlying feed raises no alarm
+		let events = System::events().len();
+		feed_price(SETUSD, Price::saturating_from_rational(80, 100), 2);
+		assert_eq!(System::events().len(), events);
+		assert_eq!(PricesModule::peg_deviation_severity(SETUSD), None);
+		assert_eq!(PricesModule::price_history(SETUSD), vec![Price::one()]);
+
+		// median of 1.05, 1 and 1.01
+		feed_price(SETUSD, Price::saturating_from_rational(105, 100), 3);
+		System::assert_last_event(Event::PricesModule(crate::Event::PegDeviationInfo(
+			SETUSD,
+			Price::saturating_from_rational(101, 100),
+			Permill::from_percent(1),
+		)));
+		assert_eq!(PricesModule::peg_deviation_severity(SETUSD), Some(DeviationSeverity::Info));
+	});
+}
+
+#[test]
+fn set_setter_basket_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		let basket = vec![(SETUSD, Permill::from_percent(60)), (DNAR, Permill::from_percent(40))];
+		assert_noop!(
+			PricesModule::set_setter_basket(Origin::signed(5), basket.clone()),
+			BadOrigin
+		);
+		assert_noop!(
+			PricesModule::set_setter_basket(
+				Origin::signed(1),
+				vec![(SETUSD, Permill::from_percent(60)), (DNAR, Permill::from_percent(30))]
+			),
+			Error::<Runtime>::InvalidSetterBasket
+		);
+		assert_noop!(
+			PricesModule::set_setter_basket(
+				Origin::signed(1),
+				vec![(DNAR, Permill::from_percent(60)), (DNAR, Permill::from_percent(40))]
+			),
+			Error::<Runtime>::InvalidSetterBasket
+		);
+		assert_noop!(
+			PricesModule::set_setter_basket(
+				Origin::signed(1),
+				vec![(SETR, Permill::from_percent(60)), (DNAR, Permill::from_percent(40))]
+			),
+			Error::<Runtime>::InvalidSetterBasket
+		);
+		assert_noop!(
+			PricesModule::set_setter_basket(
+				Origin::signed(1),
+				vec![
+					(SETUSD, Permill::from_percent(25)),
+					(DNAR, Permill::from_percent(25)),
+					(SERP, Permill::from_percent(25)),
+					(SETM, Permill::from_percent(25))
+				]
+			),
+			Error::<Runtime>::InvalidSetterBasket
+		);
+
+		assert_ok!(PricesModule::set_setter_basket(Origin::signed(1), basket.clone()));
+		System::assert_last_event(Event::PricesModule(crate::Event::SetterBasketUpdated(basket.clone())));
+		assert_eq!(PricesModule::setter_basket(), basket);
+
+		assert_ok!(PricesModule::set_setter_basket(Origin::signed(1), vec![]));
+		System::assert_last_event(Event::PricesModule(crate::Event::SetterBasketUpdated(vec![])));
+		assert_eq!(PricesModule::setter_basket(), vec![]);
+	});
+}
+
+#[test]
+fn get_setter_basket_price_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		// no basket, SETR is valued at the fixed price
+		assert_eq!(PricesModule::get_setter_basket_price(), None);
+		assert_eq!(PricesModule::access_price(SETR), Some(Price::saturating_from_rational(1, 4)));
+
+		// (0.99 * 60% + 100 * 40%) * $0.25
+		assert_ok!(PricesModule::set_setter_basket(
+			Origin::signed(1),
+			vec![(SETUSD, Permill::from_percent(60)), (DNAR, Permill::from_percent(40))]
+		));
+		assert_eq!(
+			PricesModule::get_setter_basket_price(),
+			Some(Price::saturating_from_rational(101485, 10000))
+		);
+		assert_eq!(
+			PricesModule::access_price(SETR),
+			Some(Price::saturating_from_rational(101485, 10000))
+		);
+
+		// SETUSD has no oracle price after the change
+		mock_oracle_update();
+		assert_eq!(PricesModule::get_setter_basket_price(), None);
+		assert_eq!(PricesModule::access_price(SETR), None);
+
+		// the basket is at parity with SETUSD, SETR is valued at the fixed price
+		mock_oracle_feed(SETUSD, Some(Price::one()));
+		mock_oracle_feed(DNAR, Some(Price::one()));
+		assert_eq!(PricesModule::get_setter_basket_price(), Some(Price::saturating_from_rational(1, 4)));
+		assert_eq!(PricesModule::access_price(SETR), Some(Price::saturating_from_rational(1, 4)));
+	});
+}
+
+#[test]
+fn dex_implied_price_is_fallback() {
+	ExtBuilder::default().build().execute_with(|| {
+		// the SETUSD reserve of the DNAR pool is below the minimum liquidity
+		assert_eq!(PricesModule::get_dex_implied_price(DNAR), None);
+		assert_eq!(PricesModule::get_dex_implied_price(SERP), None);
+
+		// 2_000_000 SETUSD / 1_000_000 HELP
+		assert_eq!(
+			PricesModule::get_dex_implied_price(HELP),
+			Some(Price::saturating_from_integer(2u128))
+		);
+		assert_eq!(
+			PricesModule::access_price(HELP),
+			Price::checked_from_rational(
+				Price::saturating_from_integer(2u128).into_inner(),
+				10u128.pow(MockCurrencyIdMapping::decimals(HELP).unwrap().into())
+			)
+		);
+
+		// the implied price is also rejected outside the price bounds
+		assert_ok!(PricesModule::set_price_bounds(
+			Origin::signed(1),
+			HELP,
+			Some((Price::saturating_from_integer(3u128), Price::saturating_from_integer(5u128)))
+		));
+		assert_eq!(PricesModule::get_dex_implied_price(HELP), None);
+		assert_eq!(PricesModule::access_price(HELP), None);
+	});
+}
+
+#[test]
+fn dex_implied_price_is_not_fallback_for_fed_currency() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert!(PricesModule::get_dex_implied_price(HELP).is_some());
+
+		// an anomalous oracle price is not replaced by the DEX price
+		assert_ok!(PricesModule::set_price_bounds(
+			Origin::signed(1),
+			HELP,
+			Some((Price::saturating_from_integer(1u128), Price::saturating_from_integer(5u128)))
+		));
+		mock_oracle_feed(HELP, Some(Price::saturating_from_integer(10u128)));
+		assert!(PricesModule::get_dex_implied_price(HELP).is_some());
+		assert_eq!(PricesModule::access_price(HELP), None);
+
+		// a stale oracle price is not replaced by the DEX price
+		assert_ok!(PricesModule::set_price_bounds(Origin::signed(1), HELP, None));
+		mock_oracle_feed(HELP, Some(Price::saturating_from_integer(3u128)));
+		LastPriceUpdate::<Runtime>::insert(HELP, 1);
+		System::set_block_number(12);
+		assert!(PricesModule::is_price_stale(HELP));
+		assert!(PricesModule::get_dex_implied_price(HELP).is_some());
+		assert_eq!(PricesModule::access_price(HELP), None);
+		assert_eq!(RealTimePriceProvider::<Runtime>::get_price(HELP), None);
+	});
+}
+
+#[test]
+fn get_moving_average_price_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let adjustment_multiplier = 10u128.pow(MockCurrencyIdMapping::decimals(SERP).unwrap().into());
+
+		assert_eq!(PricesModule::price_history(SERP), vec![]);
+		assert_eq!(PricesModule::get_moving_average_price(SERP, 1), None);
+
+		feed_price(SERP, Price::saturating_from_integer(100u128), 1);
+		feed_price(SERP, Price::saturating_from_integer(200u128), 2);
+		feed_price(SERP, Price::saturating_from_integer(300u128), 3);
+		assert_eq!(
+			PricesModule::price_history(SERP),
+			vec![
+				Price::saturating_from_integer(100u128),
+				Price::saturating_from_integer(200u128),
+				Price::saturating_from_integer(300u128)
+			]
+		);
+		assert_eq!(PricesModule::get_moving_average_price(SERP, 0), None);
+		assert_eq!(
+			PricesModule::get_moving_average_price(SERP, 3),
+			Price::checked_from_rational(Price::saturating_from_integer(200u128).into_inner(), adjustment_multiplier)
+		);
+
+		// the oldest price is dropped over `MaxPriceHistory`
+		feed_price(SERP, Price::saturating_from_integer(700u128), 4);
+		assert_eq!(
+			PricesModule::price_history(SERP),
+			vec![
+				Price::saturating_from_integer(200u128),
+				Price::saturating_from_integer(300u128),
+				Price::saturating_from_integer(700u128)
+			]
+		);
+		assert_eq!(
+			PricesModule::get_moving_average_price(SERP, 2),
+			Price::checked_from_rational(Price::saturating_from_integer(500u128).into_inner(), adjustment_multiplier)
+		);
+		assert_eq!(PricesModule::get_moving_average_price(SERP, 4), None);
+
+		// anomalous prices are not recorded
+		assert_ok!(PricesModule::set_price_bounds(
+			Origin::signed(1),
+			SERP,
+			Some((Price::saturating_from_integer(100u128), Price::saturating_from_integer(1000u128)))
+		));
+		feed_price(SERP, Price::saturating_from_integer(5000u128), 5);
+		assert_eq!(PricesModule::price_history(SERP).last(), Some(&Price::saturating_from_integer(700u128)));
+	});
+}
+
+#[test]
+fn price_history_records_combined_price_once_per_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		// the feeds of single operators are not recorded, only the combined price on finalize
+		mock_oracle_feed(SERP, Some(Price::saturating_from_integer(100u128)));
+		PricesModule::on_new_data(&1, &SERP, &Price::saturating_from_integer(90u128));
+		PricesModule::on_new_data(&2, &SERP, &Price::saturating_from_integer(110u128));
+		assert_eq!(PricesModule::price_history(SERP), vec![]);
+		PricesModule::on_finalize(1);
+		assert_eq!(PricesModule::price_history(SERP), vec![Price::saturating_from_integer(100u128)]);
+		assert_eq!(PricesModule::last_price_update(SERP), Some(1));
+
+		// an anomalous feed of a single operator is not rejected while the combined price is within bounds
+		assert_ok!(PricesModule::set_price_bounds(
+			Origin::signed(1),
+			SERP,
+			Some((Price::saturating_from_integer(50u128), Price::saturating_from_integer(150u128)))
+		));
+		System::set_block_number(2);
+		PricesModule::on_new_data(&1, &SERP, &Price::saturating_from_integer(5000u128));
+		PricesModule::on_finalize(2);
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			Event::PricesModule(crate::Event::AnomalousPriceRejected(..))
+		)));
+		assert_eq!(
+			PricesModule::price_history(SERP),
+			vec![Price::saturating_from_integer(100u128), Price::saturating_from_integer(100u128)]
+		);
+		assert_eq!(PricesModule::last_price_update(SERP), Some(2));
+
+		// blocks without feeds record nothing
+		PricesModule::on_finalize(3);
+		assert_eq!(PricesModule::price_history(SERP).len(), 2);
+		assert_eq!(PricesModule::last_price_update(SERP), Some(2));
+	});
+}
+
+#[test]
+fn access_price_of_erc20_currency() {
+	ExtBuilder::default().build().execute_with(|| {
+		// 1 USD, right shift the decimal point 6 places
+		assert_eq!(
+			PricesModule::access_price(ERC20_USDC),
+			Some(Price::saturating_from_rational(1, 1_000_000))
+		);
+
+		// 50000 USD, right shift the decimal point 8 places
+		assert_eq!(
+			PricesModule::access_price(ERC20_WBTC),
+			Some(Price::saturating_from_rational(50000, 100_000_000))
+		);
+
+		// 3000 USD implied by DEX, right shift the decimal point 18 places
+		assert_eq!(
+			PricesModule::get_dex_implied_price(ERC20_WETH),
+			Some(Price::saturating_from_integer(3000u128))
+		);
+		assert_eq!(
+			PricesModule::access_price(ERC20_WETH),
+			Some(Price::saturating_from_rational(3000, 1_000_000_000_000_000_000u128))
+		);
+
+		// 1 basic unit of WBTC is worth 500 basic units of USDC
+		assert_eq!(
+			RealTimePriceProvider::<Runtime>::get_relative_price(ERC20_WBTC, ERC20_USDC),
+			Some(Price::saturating_from_integer(500u128))
+		);
+
+		// ERC20 tokens without mapped decimals have no price
+		assert_eq!(PricesModule::access_price(CurrencyId::Erc20(H160([1u8; 20]))), None);
+	});
+}
+
+#[test]
+fn rename_currency_ids_migration_works() {
+	use frame_support::{
+		parameter_types,
+		traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	};
+
+	parameter_types! {
+		pub Renames: Vec<(CurrencyId, CurrencyId)> = vec![(ERC20_USDC, ERC20_WBTC)];
+	}
+
+	ExtBuilder::default().build().execute_with(|| {
+		StorageVersion::new(0).put::<PricesModule>();
+		LockedPrice::<Runtime>::insert(ERC20_USDC, Price::saturating_from_integer(1));
+		PriceHistory::<Runtime>::mutate(ERC20_USDC, |history| history.try_push(Price::saturating_from_integer(1)))
+			.unwrap();
+		SetterBasket::<Runtime>::put(vec![(ERC20_USDC, Permill::from_percent(100))]);
+		PegDeviationSeverities::<Runtime>::insert(ERC20_USDC, DeviationSeverity::Warning);
+
+		migrations::RenameCurrencyIds::<Runtime, Renames>::on_runtime_upgrade();
+
+		assert_eq!(PricesModule::locked_price(ERC20_USDC), None);
+		assert_eq!(PricesModule::locked_price(ERC20_WBTC), Some(Price::saturating_from_integer(1)));
+		assert_eq!(PricesModule::price_history(ERC20_USDC), vec![]);
+		assert_eq!(PricesModule::price_history(ERC20_WBTC), vec![Price::saturating_from_integer(1)]);
+		assert_eq!(PricesModule::setter_basket(), vec![(ERC20_WBTC, Permill::from_percent(100))]);
+		assert_eq!(PricesModule::peg_deviation_severity(ERC20_USDC), None);
+		assert_eq!(PricesModule::peg_deviation_severity(ERC20_WBTC), Some(DeviationSeverity::Warning));
+		assert_eq!(PricesModule::on_chain_storage_version(), 1);
+
+		// a second run is a no-op
+		LockedPrice::<Runtime>::insert(ERC20_USDC, Price::saturating_from_integer(2));
+		migrations::RenameCurrencyIds::<Runtime, Renames>::on_runtime_upgrade();
+		assert_eq!(PricesModule::locked_price(ERC20_USDC), Some(Price::saturating_from_integer(2)));
+		assert_eq!(PricesModule::locked_price(ERC20_WBTC), Some(Price::saturating_from_integer(1)));
+	});
+}
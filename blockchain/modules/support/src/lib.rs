@@ -32,9 +32,9 @@ use primitives::{
 use scale_info::TypeInfo;
 use sp_core::H160;
 use sp_runtime::{
-	traits::{AtLeast32BitUnsigned, CheckedDiv, MaybeSerializeDeserialize},
+	traits::{AtLeast32BitUnsigned, CheckedDiv, MaybeSerializeDeserialize, One, Saturating, Zero},
 	transaction_validity::TransactionValidityError,
-	DispatchError, DispatchResult, FixedU128, RuntimeDebug,
+	DispatchError, DispatchResult, FixedPointNumber, FixedU128, RuntimeDebug,
 };
 use sp_std::{
 	cmp::{Eq, PartialEq},
@@ -43,12 +43,25 @@ use sp_std::{
 };
 
 pub mod mocks;
+mod tests;
 
 pub type Price = FixedU128;
 pub type ExchangeRate = FixedU128;
 pub type Ratio = FixedU128;
 pub type Rate = FixedU128;
 
+/// The amount by which to change `supply` when the peg is off by
+/// `numerator / denominator`: `supply * (numerator / denominator - 1)`.
+///
+/// Pass the larger side as `numerator`; the change is zero if `numerator`
+/// is not above `denominator`, or if `denominator` is zero.
+pub fn calculate_supply_change(numerator: AsBalance, denominator: AsBalance, supply: AsBalance) -> AsBalance {
+	match Ratio::checked_from_rational(numerator, denominator) {
+		Some(ratio) => ratio.saturating_sub(Ratio::one()).saturating_mul_int(supply),
+		None => Zero::zero(),
+	}
+}
+
 pub trait RiskManager<AccountId, CurrencyId, Balance, DebitBalance> {
 	fn get_debit_value(currency_id: CurrencyId, debit_balance: DebitBalance) -> Balance;
 
@@ -244,6 +257,84 @@ pub enum SwapLimit<Balance> {
 	ExactTarget(Balance, Balance),
 }
 
+/// Why a list of currencies is not a valid `SwapPath`.
+#[derive(RuntimeDebug, Clone, Copy, PartialEq, Eq)]
+pub enum SwapPathError {
+	/// The path has fewer than two currencies.
+	TooShort,
+	/// The path is longer than the allowed maximum.
+	TooLong,
+	/// The path starts and ends with the same currency.
+	IdenticalEndpoints,
+	/// A currency appears more than once in the path.
+	DuplicateCurrency,
+}
+
+impl From<SwapPathError> for &'static str {
+	fn from(e: SwapPathError) -> &'static str {
+		match e {
+			SwapPathError::TooShort => "swap path is too short",
+			SwapPathError::TooLong => "swap path is too long",
+			SwapPathError::IdenticalEndpoints => "swap path starts and ends with the same currency",
+			SwapPathError::DuplicateCurrency => "swap path contains a currency more than once",
+		}
+	}
+}
+
+impl From<SwapPathError> for DispatchError {
+	fn from(e: SwapPathError) -> DispatchError {
+		DispatchError::Other(e.into())
+	}
+}
+
+/// A DEX swap path from `supply_currency_id` to `target_currency_id`, at
+/// least two currencies long and without repeated currencies.
+#[derive(RuntimeDebug, Clone, PartialEq, Eq)]
+pub struct SwapPath<CurrencyId>(Vec<CurrencyId>);
+
+impl<CurrencyId: PartialEq> SwapPath<CurrencyId> {
+	pub fn try_new(path: Vec<CurrencyId>) -> Result<Self, SwapPathError> {
+		if path.len() < 2 {
+			return Err(SwapPathError::TooShort);
+		}
+		if path.first() == path.last() {
+			return Err(SwapPathError::IdenticalEndpoints);
+		}
+		if path.iter().enumerate().any(|(i, c)| path[i + 1..].contains(c)) {
+			return Err(SwapPathError::DuplicateCurrency);
+		}
+		Ok(Self(path))
+	}
+
+	/// Same as `try_new`, also rejecting paths longer than `max_length`.
+	pub fn try_new_bounded(path: Vec<CurrencyId>, max_length: u32) -> Result<Self, SwapPathError> {
+		if path.len() > max_length as usize {
+			return Err(SwapPathError::TooLong);
+		}
+		Self::try_new(path)
+	}
+
+	pub fn supply_currency_id(&self) -> &CurrencyId {
+		self.0.first().expect("a swap path has at least two currencies")
+	}
+
+	pub fn target_currency_id(&self) -> &CurrencyId {
+		self.0.last().expect("a swap path has at least two currencies")
+	}
+
+	pub fn into_inner(self) -> Vec<CurrencyId> {
+		self.0
+	}
+}
+
+impl<CurrencyId> sp_std::ops::Deref for SwapPath<CurrencyId> {
+	type Target = [CurrencyId];
+
+	fn deref(&self) -> &[CurrencyId] {
+		&self.0
+	}
+}
+
 // #[derive(RuntimeDebug, Encode, Decode, Clone, Copy, PartialEq, TypeInfo)]
 // pub enum SerpingStatus<BlockNumber> {
 // 	/// Enable/Activate serping of setcurrencies (period).
@@ -257,30 +348,30 @@ pub trait DEXManager<AccountId, CurrencyId, Balance> {
 
 	fn get_liquidity_token_address(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> Option<H160>;
 
-	fn get_swap_amount(path: &[CurrencyId], limit: SwapLimit<Balance>) -> Option<(Balance, Balance)>;
+	fn get_swap_amount(path: &SwapPath<CurrencyId>, limit: SwapLimit<Balance>) -> Option<(Balance, Balance)>;
 
 	fn get_best_price_swap_path(
 		supply_currency_id: CurrencyId,
 		target_currency_id: CurrencyId,
 		limit: SwapLimit<Balance>,
 		alternative_path_joint_list: Vec<Vec<CurrencyId>>,
-	) -> Option<Vec<CurrencyId>>;
+	) -> Option<SwapPath<CurrencyId>>;
 
 	fn swap_with_specific_path(
 		who: &AccountId,
-		path: &[CurrencyId],
+		path: &SwapPath<CurrencyId>,
 		limit: SwapLimit<Balance>,
 	) -> sp_std::result::Result<(Balance, Balance), DispatchError>;
 
 	fn buyback_swap_with_specific_path(
 		who: &AccountId,
-		path: &[CurrencyId],
+		path: &SwapPath<CurrencyId>,
 		limit: SwapLimit<Balance>,
 	) -> sp_std::result::Result<(Balance, Balance), DispatchError>;
 
 	fn swap_with_exact_target(
 		who: &AccountId,
-		path: &[CurrencyId],
+		path: &SwapPath<CurrencyId>,
 		exact_target_amount: Balance,
 		max_supply_amount: Balance,
 	) -> DispatchResult;
@@ -316,7 +407,7 @@ where
 		Some(Default::default())
 	}
 
-	fn get_swap_amount(_path: &[CurrencyId], _limit: SwapLimit<Balance>) -> Option<(Balance, Balance)> {
+	fn get_swap_amount(_path: &SwapPath<CurrencyId>, _limit: SwapLimit<Balance>) -> Option<(Balance, Balance)> {
 		Some(Default::default())
 	}
 
@@ -325,13 +416,13 @@ where
 		_target_currency_id: CurrencyId,
 		_limit: SwapLimit<Balance>,
 		_alternative_path_joint_list: Vec<Vec<CurrencyId>>,
-	) -> Option<Vec<CurrencyId>> {
-		Some(Default::default())
+	) -> Option<SwapPath<CurrencyId>> {
+		None
 	}
 
 	fn swap_with_specific_path(
 		_who: &AccountId,
-		_path: &[CurrencyId],
+		_path: &SwapPath<CurrencyId>,
 		_limit: SwapLimit<Balance>,
 	) -> sp_std::result::Result<(Balance, Balance), DispatchError> {
 		Ok(Default::default())
@@ -339,7 +430,7 @@ where
 
 	fn buyback_swap_with_specific_path(
 		_who: &AccountId,
-		_path: &[CurrencyId],
+		_path: &SwapPath<CurrencyId>,
 		_limit: SwapLimit<Balance>,
 	) -> sp_std::result::Result<(Balance, Balance), DispatchError> {
 		Ok(Default::default())
@@ -347,7 +438,7 @@ where
 
 	fn swap_with_exact_target(
 		_who: &AccountId,
-		_path: &[CurrencyId],
+		_path: &SwapPath<CurrencyId>,
 		_exact_target_amount: Balance,
 		_max_supply_amount: Balance,
 	) -> DispatchResult {
@@ -377,13 +468,22 @@ where
 	}
 }
 
-/// An abstraction of serp treasury for the SERP (Setheum Elastic Reserve Protocol).
-pub trait SerpTreasury<AccountId> {
+/// The read-only half of the serp treasury for the SERP (Setheum Elastic
+/// Reserve Protocol).
+pub trait SerpTreasuryInspect<AccountId> {
 	type Balance;
 	type CurrencyId;
 
+	/// See [`calculate_supply_change`].
 	fn calculate_supply_change(numerator: Self::Balance, denominator: Self::Balance, supply: Self::Balance) -> Self::Balance;
 
+	/// get the minimum supply of a setcurrency - by key
+	fn get_minimum_supply(currency_id: Self::CurrencyId) -> Self::Balance;
+}
+
+/// The state-mutating half of the serp treasury for the SERP (Setheum
+/// Elastic Reserve Protocol).
+pub trait SerpTreasuryMutate<AccountId>: SerpTreasuryInspect<AccountId> {
 	fn serp_tes_now() -> DispatchResult;
 
 	/// Deliver System StableCurrency Inflation
@@ -419,9 +519,6 @@ pub trait SerpTreasury<AccountId> {
 	/// buy back and burn surplus(stable currencies) with swap by DEX.
 	fn on_serpdown(currency_id: Self::CurrencyId, amount: Self::Balance) -> DispatchResult;
 
-	/// get the minimum supply of a setcurrency - by key
-	fn get_minimum_supply(currency_id: Self::CurrencyId) -> Self::Balance;
-
 	/// issue standard to `who`
 	fn issue_standard(currency_id: Self::CurrencyId, who: &AccountId, standard: Self::Balance) -> DispatchResult;
 
@@ -441,7 +538,13 @@ pub trait SerpTreasury<AccountId> {
 	fn claim_cashdrop(currency_id: Self::CurrencyId, who: &AccountId, transfer_amount: Self::Balance) -> DispatchResult;
 }
 
-pub trait SerpTreasuryExtended<AccountId>: SerpTreasury<AccountId> {
+/// An abstraction of serp treasury for the SERP (Setheum Elastic Reserve
+/// Protocol), for dependents that need both halves.
+pub trait SerpTreasury<AccountId>: SerpTreasuryInspect<AccountId> + SerpTreasuryMutate<AccountId> {}
+
+impl<AccountId, T: SerpTreasuryInspect<AccountId> + SerpTreasuryMutate<AccountId>> SerpTreasury<AccountId> for T {}
+
+pub trait SerpTreasuryExtended<AccountId>: SerpTreasuryMutate<AccountId> {
 	/// When SetCurrency needs SerpDown
 	fn buyback_swap_with_exact_supply(
 		from_currency_id: Self::CurrencyId,
@@ -541,6 +644,11 @@ pub trait LockablePrice<CurrencyId> {
 	fn unlock_price(currency_id: CurrencyId) -> DispatchResult;
 }
 
+pub trait TwapPriceProvider<CurrencyId, BlockNumber> {
+	/// Get the time-weighted average price of `currency_id` over the last `window` blocks.
+	fn get_twap_price(currency_id: CurrencyId, window: BlockNumber) -> Option<Price>;
+}
+
 pub trait ExchangeRateProvider {
 	fn get_exchange_rate() -> ExchangeRate;
 }
@@ -818,3 +926,16 @@ impl CurrencyIdMapping for () {
 		None
 	}
 }
+
+/// A mapping between ForeignAssetId and the metadata of the foreign asset
+/// registered at runtime.
+pub trait ForeignAssetIdMapping<ForeignAssetId, AssetMetadata> {
+	/// Returns the AssetMetadata associated with a given ForeignAssetId.
+	fn get_asset_metadata(foreign_asset_id: ForeignAssetId) -> Option<AssetMetadata>;
+}
+
+impl<ForeignAssetId, AssetMetadata> ForeignAssetIdMapping<ForeignAssetId, AssetMetadata> for () {
+	fn get_asset_metadata(_foreign_asset_id: ForeignAssetId) -> Option<AssetMetadata> {
+		None
+	}
+}
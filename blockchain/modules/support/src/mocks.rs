@@ -21,7 +21,10 @@
 use crate::{AddressMapping, CurrencyId, CurrencyIdMapping};
 use codec::Encode;
 use frame_support::pallet_prelude::DispatchResult;
-use primitives::{currency::TokenInfo, evm::EvmAddress, H160_POSITION_TOKEN, H160_PREFIX_TOKEN};
+use primitives::{
+	currency::TokenInfo, evm::EvmAddress, H160_POSITION_STABLE_ASSET, H160_POSITION_TOKEN, H160_PREFIX_STABLE_ASSET,
+	H160_PREFIX_TOKEN,
+};
 use sp_core::{crypto::AccountId32, H160};
 use sp_io::hashing::blake2_256;
 use sp_std::{
@@ -96,6 +99,15 @@ impl CurrencyIdMapping for MockCurrencyIdMapping {
 		let address = v.as_bytes();
 		if address.starts_with(&H160_PREFIX_TOKEN) {
 			address[H160_POSITION_TOKEN].try_into().map(CurrencyId::Token).ok()
+		} else if address.starts_with(&H160_PREFIX_STABLE_ASSET)
+			&& address[H160_PREFIX_STABLE_ASSET.len()..H160_POSITION_STABLE_ASSET.start]
+				.iter()
+				.all(|b| *b == 0)
+		{
+			address[H160_POSITION_STABLE_ASSET]
+				.try_into()
+				.map(|v| CurrencyId::StableAssetPoolToken(u32::from_be_bytes(v)))
+				.ok()
 		} else {
 			None
 		}
@@ -0,0 +1,77 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the support module.
+
+#![cfg(test)]
+
+use super::*;
+
+const SUPPLY: AsBalance = 1_000_000_000;
+
+#[test]
+fn calculate_supply_change_is_proportional_to_small_deviations() {
+	// 0.1%
+	assert_eq!(calculate_supply_change(1_001, 1_000, SUPPLY), 1_000_000);
+	// 1%
+	assert_eq!(calculate_supply_change(101, 100, SUPPLY), 10_000_000);
+	// 5%
+	assert_eq!(calculate_supply_change(105, 100, SUPPLY), 50_000_000);
+	// 10%
+	assert_eq!(calculate_supply_change(110, 100, SUPPLY), 100_000_000);
+	// 50%
+	assert_eq!(calculate_supply_change(150, 100, SUPPLY), 500_000_000);
+}
+
+#[test]
+fn calculate_supply_change_without_deviation_is_zero() {
+	assert_eq!(calculate_supply_change(100, 100, SUPPLY), 0);
+	assert_eq!(calculate_supply_change(99, 100, SUPPLY), 0);
+	assert_eq!(calculate_supply_change(100, 0, SUPPLY), 0);
+	assert_eq!(calculate_supply_change(101, 100, 0), 0);
+}
+
+#[test]
+fn swap_path_try_new_works() {
+	let path = SwapPath::try_new(vec![1, 2, 3]).unwrap();
+	assert_eq!(*path.supply_currency_id(), 1);
+	assert_eq!(*path.target_currency_id(), 3);
+	assert_eq!(&path[..], &[1, 2, 3]);
+	assert_eq!(path.into_inner(), vec![1, 2, 3]);
+
+	assert_eq!(SwapPath::<u32>::try_new(vec![]), Err(SwapPathError::TooShort));
+	assert_eq!(SwapPath::try_new(vec![1]), Err(SwapPathError::TooShort));
+	assert_eq!(SwapPath::try_new(vec![1, 1]), Err(SwapPathError::IdenticalEndpoints));
+	assert_eq!(SwapPath::try_new(vec![1, 2, 1]), Err(SwapPathError::IdenticalEndpoints));
+	assert_eq!(SwapPath::try_new(vec![1, 2, 2, 3]), Err(SwapPathError::DuplicateCurrency));
+	assert_eq!(SwapPath::try_new(vec![1, 2, 3, 2]), Err(SwapPathError::DuplicateCurrency));
+}
+
+#[test]
+fn swap_path_try_new_bounded_works() {
+	assert!(SwapPath::try_new_bounded(vec![1, 2, 3], 3).is_ok());
+	assert_eq!(SwapPath::try_new_bounded(vec![1, 2, 3], 2), Err(SwapPathError::TooLong));
+	// the other checks still apply
+	assert_eq!(SwapPath::try_new_bounded(vec![1], 3), Err(SwapPathError::TooShort));
+	assert_eq!(
+		SwapPath::try_new_bounded(vec![1, 2, 2, 3], 4),
+		Err(SwapPathError::DuplicateCurrency)
+	);
+}
@@ -53,7 +53,7 @@ use sp_runtime::{
 	FixedPointNumber, FixedPointOperand, FixedU128, Perquintill,
 };
 use sp_std::{convert::TryInto, prelude::*, vec};
-use support::{DEXManager, PriceProvider, Ratio, TransactionPayment};
+use support::{DEXManager, PriceProvider, Ratio, SwapPath, TransactionPayment};
 
 mod mock;
 mod tests;
@@ -327,6 +327,19 @@ pub mod module {
 
 		#[cfg(feature = "std")]
 		fn integrity_test() {
+			// an invalid default fee swap path would otherwise be skipped silently when
+			// charging fees.
+			for path in T::DefaultFeeSwapPathList::get() {
+				let swap_path = SwapPath::try_new_bounded(path.clone(), T::TradingPathLimit::get())
+					.unwrap_or_else(|e| panic!("invalid DefaultFeeSwapPathList path {:?}: {:?}", path, e));
+				assert_eq!(
+					*swap_path.target_currency_id(),
+					T::NativeCurrencyId::get(),
+					"DefaultFeeSwapPathList path {:?} doesn't end with the native currency",
+					path
+				);
+			}
+
 			// given weight == u64, we build multipliers from `diff` of two weight values,
 			// which can at most be MaximumBlockWeight. Make sure that this can fit in a
 			// multiplier without loss.
@@ -379,14 +392,14 @@ pub mod module {
 			let who = ensure_signed(origin)?;
 
 			if let Some(path) = fee_swap_path {
-				let path: BoundedVec<CurrencyId, T::TradingPathLimit> =
-					path.try_into().map_err(|_| Error::<T>::InvalidSwapPath)?;
+				let path = SwapPath::try_new_bounded(path, T::TradingPathLimit::get())
+					.map_err(|_| Error::<T>::InvalidSwapPath)?;
 				ensure!(
-					path.len() > 1
-						&& path[0] != T::NativeCurrencyId::get()
-						&& path[path.len() - 1] == T::NativeCurrencyId::get(),
+					*path.target_currency_id() == T::NativeCurrencyId::get(),
 					Error::<T>::InvalidSwapPath
 				);
+				let path: BoundedVec<CurrencyId, T::TradingPathLimit> =
+					path.into_inner().try_into().map_err(|_| Error::<T>::InvalidSwapPath)?;
 				AlternativeFeeSwapPath::<T>::insert(&who, &path);
 			} else {
 				AlternativeFeeSwapPath::<T>::remove(&who);
@@ -599,37 +612,36 @@ where
 				};
 
 			for trading_path in fee_swap_path_list {
-				match trading_path.last() {
-					Some(target_currency_id) if *target_currency_id == native_currency_id => {
-						let supply_currency_id = *trading_path.first().expect("these's first guaranteed by match");
-						// calculate the supply limit according to oracle price and the slippage limit,
-						// if oracle price is not avalible, do not limit
-						let max_supply_limit = if let Some(target_price) =
-							T::PriceSource::get_relative_price(*target_currency_id, supply_currency_id)
-						{
-							Ratio::one()
-								.saturating_sub(T::MaxSwapSlippageCompareToOracle::get())
-								.reciprocal()
-								.unwrap_or_else(Ratio::max_value)
-								.saturating_mul_int(target_price.saturating_mul_int(amount))
-						} else {
-							PalletBalanceOf::<T>::max_value()
-						};
-
-						if T::DEX::swap_with_exact_target(
-							who,
-							&trading_path,
-							amount.unique_saturated_into(),
-							<T as Config>::MultiCurrency::free_balance(supply_currency_id, who)
-								.min(max_supply_limit.unique_saturated_into()),
-						)
-						.is_ok()
-						{
-							// successfully swap, break iteration
-							break;
-						}
-					}
-					_ => {}
+				let trading_path = match SwapPath::try_new_bounded(trading_path, T::TradingPathLimit::get()) {
+					Ok(path) if *path.target_currency_id() == native_currency_id => path,
+					_ => continue,
+				};
+				let supply_currency_id = *trading_path.supply_currency_id();
+				// calculate the supply limit according to oracle price and the slippage limit,
+				// if oracle price is not avalible, do not limit
+				let max_supply_limit = if let Some(target_price) =
+					T::PriceSource::get_relative_price(native_currency_id, supply_currency_id)
+				{
+					Ratio::one()
+						.saturating_sub(T::MaxSwapSlippageCompareToOracle::get())
+						.reciprocal()
+						.unwrap_or_else(Ratio::max_value)
+						.saturating_mul_int(target_price.saturating_mul_int(amount))
+				} else {
+					PalletBalanceOf::<T>::max_value()
+				};
+
+				if T::DEX::swap_with_exact_target(
+					who,
+					&trading_path,
+					amount.unique_saturated_into(),
+					<T as Config>::MultiCurrency::free_balance(supply_currency_id, who)
+						.min(max_supply_limit.unique_saturated_into()),
+				)
+				.is_ok()
+				{
+					// successfully swap, break iteration
+					break;
 				}
 			}
 		}
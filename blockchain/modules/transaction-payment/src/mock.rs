@@ -34,7 +34,7 @@ use smallvec::smallvec;
 use sp_core::{crypto::AccountId32, H256};
 use sp_runtime::{testing::Header, traits::IdentityLookup, Perbill};
 use sp_std::cell::RefCell;
-use support::{mocks::MockAddressMapping, Price, SerpTreasury};
+use support::{mocks::MockAddressMapping, Price, SerpTreasuryInspect, SerpTreasuryMutate};
 
 pub type AccountId = AccountId32;
 pub type BlockNumber = u64;
@@ -131,7 +131,7 @@ impl pallet_balances::Config for Runtime {
 pub type AdaptedBasicCurrency = module_currencies::BasicCurrencyAdapter<Runtime, PalletBalances, Amount, BlockNumber>;
 
 pub struct MockSerpTreasury;
-impl SerpTreasury<AccountId> for MockSerpTreasury {
+impl SerpTreasuryInspect<AccountId> for MockSerpTreasury {
 	type Balance = Balance;
 	type CurrencyId = CurrencyId;
 
@@ -143,6 +143,15 @@ impl SerpTreasury<AccountId> for MockSerpTreasury {
 		unimplemented!()
 	}
 
+	/// get the minimum supply of a setcurrency - by key
+	fn get_minimum_supply(
+		_currency_id: CurrencyId
+	) -> Balance {
+		unimplemented!()
+	}
+}
+
+impl SerpTreasuryMutate<AccountId> for MockSerpTreasury {
 	fn serp_tes_now() -> DispatchResult {
 		unimplemented!()
 	}
@@ -224,13 +233,6 @@ impl SerpTreasury<AccountId> for MockSerpTreasury {
 		unimplemented!()
 	}
 
-	/// get the minimum supply of a setcurrency - by key
-	fn get_minimum_supply(
-		_currency_id: CurrencyId
-	) -> Balance {
-		unimplemented!()
-	}
-
 	/// issue standard to `who`
 	fn issue_standard(
 		_currency_id: CurrencyId,
@@ -305,6 +307,7 @@ impl module_currencies::Config for Runtime {
 	type AddressMapping = MockAddressMapping;
 	type EVMBridge = ();
 	type SweepOrigin = EnsureSignedBy<Zero, AccountId>;
+	type DustRemovalWhitelistOrigin = EnsureSignedBy<Zero, AccountId>;
 	type OnDust = ();
 }
 
@@ -430,7 +433,7 @@ construct_runtime!(
 		TransactionPayment: transaction_payment::{Pallet, Call, Storage},
 		PalletBalances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
-		Currencies: module_currencies::{Pallet, Call, Event<T>},
+		Currencies: module_currencies::{Pallet, Call, Storage, Event<T>},
 		DEXModule: module_dex::{Pallet, Storage, Call, Event<T>, Config<T>},
 	}
 );
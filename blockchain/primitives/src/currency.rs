@@ -22,13 +22,16 @@
 
 use crate::{evm::EvmAddress, *};
 use bstringify::bstringify;
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use scale_info::TypeInfo;
 use sp_runtime::RuntimeDebug;
 use sp_std::{
 	convert::{Into, TryFrom},
-	prelude::*, vec,
+	fmt,
+	prelude::*,
+	str::FromStr,
+	vec,
 };
 
 #[cfg(feature = "std")]
@@ -63,6 +66,25 @@ macro_rules! create_currency_id {
 			}
 		}
 
+		impl TryFrom<&str> for TokenSymbol {
+			type Error = ();
+
+			fn try_from(v: &str) -> Result<Self, Self::Error> {
+				match v {
+					$(stringify!($symbol) => Ok(TokenSymbol::$symbol),)*
+					_ => Err(()),
+				}
+			}
+		}
+
+		impl fmt::Display for TokenSymbol {
+			fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				match self {
+					$(TokenSymbol::$symbol => f.write_str(stringify!($symbol)),)*
+				}
+			}
+		}
+
 		impl TryFrom<Vec<u8>> for CurrencyId {
 			type Error = ();
 			fn try_from(v: Vec<u8>) -> Result<CurrencyId, ()> {
@@ -83,6 +105,7 @@ macro_rules! create_currency_id {
 			fn name(&self) -> Option<&str> {
 				match self {
 					$(CurrencyId::Token(TokenSymbol::$symbol) => Some($name),)*
+					CurrencyId::StableAssetPoolToken(_) => Some("Stable Asset Pool Token"),
 					_ => None,
 				}
 			}
@@ -95,6 +118,7 @@ macro_rules! create_currency_id {
 			fn decimals(&self) -> Option<u8> {
 				match self {
 					$(CurrencyId::Token(TokenSymbol::$symbol) => Some($deci),)*
+					CurrencyId::StableAssetPoolToken(_) => Some(STABLE_ASSET_POOL_TOKEN_DECIMALS),
 					_ => None,
 				}
 			}
@@ -190,21 +214,45 @@ pub trait TokenInfo {
 	fn decimals(&self) -> Option<u8>;
 }
 
-#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, PartialOrd, Ord)]
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, PartialOrd, Ord, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub enum DexShare {
 	Token(TokenSymbol),
 	Erc20(EvmAddress),
+	ForeignAsset(ForeignAssetId),
+}
+
+/// Stable Asset Pool ID
+pub type StableAssetPoolId = u32;
+
+/// Foreign Asset ID, assigned by the asset registry when the asset is
+/// registered.
+pub type ForeignAssetId = u16;
+
+/// Metadata of an asset registered at runtime.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AssetMetadata<Balance> {
+	pub name: Vec<u8>,
+	pub symbol: Vec<u8>,
+	pub decimals: u8,
+	pub minimal_balance: Balance,
 }
 
-#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, PartialOrd, Ord)]
+/// The decimals of all stable asset pool tokens, the pool shares are
+/// normalized to 18 decimals whatever the decimals of the pooled assets.
+pub const STABLE_ASSET_POOL_TOKEN_DECIMALS: u8 = 18;
+
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, PartialOrd, Ord, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub enum CurrencyId {
 	Token(TokenSymbol),
 	DexShare(DexShare, DexShare),
 	Erc20(EvmAddress),
+	StableAssetPoolToken(StableAssetPoolId),
+	ForeignAsset(ForeignAssetId),
 }
 
 impl CurrencyId {
@@ -220,10 +268,18 @@ impl CurrencyId {
 		matches!(self, CurrencyId::Erc20(_))
 	}
 
+	pub fn is_stable_asset_pool_token_currency_id(&self) -> bool {
+		matches!(self, CurrencyId::StableAssetPoolToken(_))
+	}
+
+	pub fn is_foreign_asset_currency_id(&self) -> bool {
+		matches!(self, CurrencyId::ForeignAsset(_))
+	}
+
 	pub fn is_trading_pair_currency_id(&self) -> bool {
 		matches!(
 			self,
-			CurrencyId::Token(_) | CurrencyId::Erc20(_)
+			CurrencyId::Token(_) | CurrencyId::Erc20(_) | CurrencyId::ForeignAsset(_)
 		)
 	}
 
@@ -242,14 +298,16 @@ impl CurrencyId {
 		let dex_share_0 = match currency_id_0 {
 			CurrencyId::Token(symbol) => DexShare::Token(symbol),
 			CurrencyId::Erc20(address) => DexShare::Erc20(address),
+			CurrencyId::ForeignAsset(foreign_asset_id) => DexShare::ForeignAsset(foreign_asset_id),
 			// Unsupported
-			CurrencyId::DexShare(..) => return None,
+			CurrencyId::DexShare(..) | CurrencyId::StableAssetPoolToken(_) => return None,
 		};
 		let dex_share_1 = match currency_id_1 {
 			CurrencyId::Token(symbol) => DexShare::Token(symbol),
 			CurrencyId::Erc20(address) => DexShare::Erc20(address),
+			CurrencyId::ForeignAsset(foreign_asset_id) => DexShare::ForeignAsset(foreign_asset_id),
 			// Unsupported
-			CurrencyId::DexShare(..) => return None,
+			CurrencyId::DexShare(..) | CurrencyId::StableAssetPoolToken(_) => return None,
 		};
 		Some(CurrencyId::DexShare(dex_share_0, dex_share_1))
 	}
@@ -263,6 +321,8 @@ impl CurrencyId {
 pub enum CurrencyIdType {
 	Token = 1, // 0 is prefix of precompile and predeploy
 	DexShare,
+	StableAsset,
+	ForeignAsset,
 }
 
 #[derive(
@@ -272,6 +332,7 @@ pub enum CurrencyIdType {
 pub enum DexShareType {
 	Token,
 	Erc20,
+	ForeignAsset,
 }
 
 impl From<DexShare> for u32 {
@@ -289,6 +350,12 @@ impl From<DexShare> for u32 {
 				let index = if leading_zeros > 16 { 16 } else { leading_zeros };
 				bytes[..].copy_from_slice(&address[index..index + 4][..]);
 			}
+			DexShare::ForeignAsset(foreign_asset_id) => {
+				// The first byte is zero, which no mapped Erc20 id starts with, the second byte
+				// is the DexShareType tag and the last 2 bytes are the foreign asset id.
+				bytes[1] = DexShareType::ForeignAsset.into();
+				bytes[2..].copy_from_slice(&foreign_asset_id.to_be_bytes());
+			}
 		}
 		u32::from_be_bytes(bytes)
 	}
@@ -299,6 +366,7 @@ impl Into<CurrencyId> for DexShare {
 		match self {
 			DexShare::Token(token) => CurrencyId::Token(token),
 			DexShare::Erc20(address) => CurrencyId::Erc20(address),
+			DexShare::ForeignAsset(foreign_asset_id) => CurrencyId::ForeignAsset(foreign_asset_id),
 		}
 	}
 }
@@ -308,6 +376,88 @@ impl Into<DexShareType> for DexShare {
 		match self {
 			DexShare::Token(_) => DexShareType::Token,
 			DexShare::Erc20(_) => DexShareType::Erc20,
+			DexShare::ForeignAsset(_) => DexShareType::ForeignAsset,
+		}
+	}
+}
+
+/// The ticker of a DexShare: the token symbol, `FA_{id}` for a foreign asset
+/// and the hex address for an Erc20.
+impl fmt::Display for DexShare {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			DexShare::Token(symbol) => write!(f, "{}", symbol),
+			DexShare::Erc20(address) => write!(f, "{:?}", address),
+			DexShare::ForeignAsset(foreign_asset_id) => write!(f, "FA_{}", foreign_asset_id),
+		}
+	}
+}
+
+/// Parse the decimal id of a ticker, `str::parse` alone would also accept a
+/// leading `+`.
+fn parse_ticker_id<Id: FromStr>(v: &str) -> Result<Id, ()> {
+	if v.is_empty() || !v.bytes().all(|b| b.is_ascii_digit()) {
+		return Err(());
+	}
+	v.parse().map_err(|_| ())
+}
+
+/// Parse the ticker of a DexShare, the reverse of `Display`.
+impl TryFrom<&str> for DexShare {
+	type Error = ();
+
+	fn try_from(v: &str) -> Result<Self, Self::Error> {
+		if let Some(foreign_asset_id) = v.strip_prefix("FA_") {
+			parse_ticker_id(foreign_asset_id).map(DexShare::ForeignAsset)
+		} else if let Some(address) = v.strip_prefix("0x") {
+			let mut bytes = [0u8; 20];
+			// `from_str_radix` would also accept a leading `+`.
+			if address.len() != 40 || !address.bytes().all(|b| b.is_ascii_hexdigit()) {
+				return Err(());
+			}
+			for (i, byte) in bytes.iter_mut().enumerate() {
+				*byte = u8::from_str_radix(address.get(i * 2..i * 2 + 2).ok_or(())?, 16).map_err(|_| ())?;
+			}
+			Ok(DexShare::Erc20(EvmAddress::from(bytes)))
+		} else {
+			TokenSymbol::try_from(v).map(DexShare::Token)
+		}
+	}
+}
+
+/// The ticker of a CurrencyId, e.g. `SETUSD`, or `LP_SETUSD_SETR` for the
+/// dex share of SETUSD and SETR.
+impl fmt::Display for CurrencyId {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			CurrencyId::Token(symbol) => write!(f, "{}", symbol),
+			CurrencyId::DexShare(dex_share_0, dex_share_1) => write!(f, "LP_{}_{}", dex_share_0, dex_share_1),
+			CurrencyId::Erc20(address) => write!(f, "{:?}", address),
+			CurrencyId::StableAssetPoolToken(pool_id) => write!(f, "SA_{}", pool_id),
+			CurrencyId::ForeignAsset(foreign_asset_id) => write!(f, "FA_{}", foreign_asset_id),
+		}
+	}
+}
+
+/// Parse the ticker of a CurrencyId, the reverse of `Display`, so that RPC
+/// and CLI tools can accept "SETUSD" or "LP_SETUSD_SETR".
+impl TryFrom<&str> for CurrencyId {
+	type Error = ();
+
+	fn try_from(v: &str) -> Result<Self, Self::Error> {
+		if let Some(pair) = v.strip_prefix("LP_") {
+			// The tickers of both sides may contain `_`, take the first split that parses.
+			pair.match_indices('_')
+				.find_map(|(i, _)| {
+					let dex_share_0 = DexShare::try_from(&pair[..i]).ok()?;
+					let dex_share_1 = DexShare::try_from(&pair[i + 1..]).ok()?;
+					Some(CurrencyId::DexShare(dex_share_0, dex_share_1))
+				})
+				.ok_or(())
+		} else if let Some(pool_id) = v.strip_prefix("SA_") {
+			parse_ticker_id(pool_id).map(CurrencyId::StableAssetPoolToken)
+		} else {
+			DexShare::try_from(v).map(Into::into)
 		}
 	}
 }
@@ -325,10 +475,12 @@ impl TryFrom<CurrencyId> for EvmAddress {
 				let symbol_0 = match token_symbol_0 {
 					DexShare::Token(token) => CurrencyId::Token(token).currency_id().ok_or(()),
 					DexShare::Erc20(_) => Err(()),
+					DexShare::ForeignAsset(_) => Err(()),
 				}?;
 				let symbol_1 = match token_symbol_1 {
 					DexShare::Token(token) => CurrencyId::Token(token).currency_id().ok_or(()),
 					DexShare::Erc20(_) => Err(()),
+					DexShare::ForeignAsset(_) => Err(()),
 				}?;
 
 				let mut prefix = EvmAddress::default();
@@ -336,6 +488,16 @@ impl TryFrom<CurrencyId> for EvmAddress {
 				Ok(prefix | EvmAddress::from_low_u64_be(u64::from(symbol_0) << 32 | u64::from(symbol_1)))
 			}
 			CurrencyId::Erc20(address) => Ok(address),
+			CurrencyId::StableAssetPoolToken(pool_id) => {
+				let mut prefix = EvmAddress::default();
+				prefix[0..H160_PREFIX_STABLE_ASSET.len()].copy_from_slice(&H160_PREFIX_STABLE_ASSET);
+				Ok(prefix | EvmAddress::from_low_u64_be(u64::from(pool_id)))
+			}
+			CurrencyId::ForeignAsset(foreign_asset_id) => {
+				let mut prefix = EvmAddress::default();
+				prefix[0..H160_PREFIX_FOREIGN_ASSET.len()].copy_from_slice(&H160_PREFIX_FOREIGN_ASSET);
+				Ok(prefix | EvmAddress::from_low_u64_be(u64::from(foreign_asset_id)))
+			}
 		}
 	}
 }
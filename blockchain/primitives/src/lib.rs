@@ -37,7 +37,7 @@ use sp_runtime::{
 	RuntimeDebug,
 };
 
-pub use currency::{CurrencyId, DexShare, TokenSymbol};
+pub use currency::{AssetMetadata, CurrencyId, DexShare, ForeignAssetId, StableAssetPoolId, TokenSymbol};
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -62,6 +62,12 @@ pub const MIRRORED_NFT_ADDRESS_START: u64 = 0x2000000;
 /// Mirrored LP Tokens
 /// 0x10000000000000000
 pub const MIRRORED_LP_TOKENS_ADDRESS_START: u128 = 0x10000000000000000;
+/// Mirrored Stable Asset Pool Tokens
+/// 0x20000000000000000
+pub const MIRRORED_STABLE_ASSET_TOKENS_ADDRESS_START: u128 = 0x20000000000000000;
+/// Mirrored Foreign Assets
+/// 0x30000000000000000
+pub const MIRRORED_FOREIGN_ASSET_ADDRESS_START: u128 = 0x30000000000000000;
 /// System contract address prefix
 pub const SYSTEM_CONTRACT_ADDRESS_PREFIX: [u8; 11] = [0u8; 11];
 /// Network contracts
@@ -78,17 +84,34 @@ pub const NETWORK_CONTRACT_START: u64 = 0x1000;
 /// v[11] = 1 // MIRRORED_LP_TOKENS_ADDRESS_START
 /// - v[12..16] = dex left(4 bytes)
 /// - v[16..20] = dex right(4 bytes)
+///   - Token: [0, 0, 0, token]
+///   - ForeignAsset: [0, 2, foreign asset id(2 bytes)], 2 is the DexShareType
+///   - Erc20: the first 4 non-zero bytes of the evm address, never starts with 0
+///
+/// StableAssetPoolToken
+/// v[11] = 2 // MIRRORED_STABLE_ASSET_TOKENS_ADDRESS_START
+/// - v[16..20] = stable asset pool id(4 bytes)
+///
+/// ForeignAsset
+/// v[11] = 3 // MIRRORED_FOREIGN_ASSET_ADDRESS_START
+/// - v[18..20] = foreign asset id(2 bytes)
 ///
 /// Erc20
 /// - v[0..20] = evm address(20 bytes)
 pub const H160_TYPE_TOKEN: u8 = 1;
 pub const H160_TYPE_DEXSHARE: u8 = 1;
+pub const H160_TYPE_STABLE_ASSET: u8 = 2;
+pub const H160_TYPE_FOREIGN_ASSET: u8 = 3;
 pub const H160_POSITION_TOKEN: usize = 19;
 pub const H160_POSITION_DEXSHARE_LEFT: Range<usize> = 12..16;
 pub const H160_POSITION_DEXSHARE_RIGHT: Range<usize> = 16..20;
+pub const H160_POSITION_STABLE_ASSET: Range<usize> = 16..20;
+pub const H160_POSITION_FOREIGN_ASSET: Range<usize> = 18..20;
 pub const H160_POSITION_ERC20: Range<usize> = 0..20;
 pub const H160_PREFIX_TOKEN: [u8; 19] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0];
 pub const H160_PREFIX_DEXSHARE: [u8; 12] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+pub const H160_PREFIX_STABLE_ASSET: [u8; 12] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2];
+pub const H160_PREFIX_FOREIGN_ASSET: [u8; 12] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3];
 
 /// NFT Balance type
 pub type NFTBalance = u128;
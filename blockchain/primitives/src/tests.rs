@@ -119,6 +119,59 @@ fn currency_id_try_into_evm_address_works() {
 
 	let erc20 = EvmAddress::from_str("0x1111111111111111111111111111111111111111").unwrap();
 	assert_eq!(EvmAddress::try_from(CurrencyId::Erc20(erc20)), Ok(erc20));
+
+	assert_eq!(
+		EvmAddress::try_from(CurrencyId::StableAssetPoolToken(0)),
+		Ok(EvmAddress::from_str("0x0000000000000000000000020000000000000000").unwrap())
+	);
+	assert_eq!(
+		EvmAddress::try_from(CurrencyId::StableAssetPoolToken(u32::MAX)),
+		Ok(EvmAddress::from_str("0x00000000000000000000000200000000ffffffff").unwrap())
+	);
+}
+
+#[test]
+fn foreign_asset_works() {
+	let foreign_asset = CurrencyId::ForeignAsset(258);
+	assert!(foreign_asset.is_foreign_asset_currency_id());
+	assert!(foreign_asset.is_trading_pair_currency_id());
+	assert_eq!(
+		EvmAddress::try_from(foreign_asset),
+		Ok(EvmAddress::from_str("0x0000000000000000000000030000000000000102").unwrap())
+	);
+
+	assert_eq!(Into::<u32>::into(DexShare::ForeignAsset(258)), 0x00020102);
+	assert_eq!(
+		CurrencyId::join_dex_share_currency_id(foreign_asset, CurrencyId::Token(TokenSymbol::SEE)),
+		Some(CurrencyId::DexShare(
+			DexShare::ForeignAsset(258),
+			DexShare::Token(TokenSymbol::SEE)
+		))
+	);
+	assert_eq!(
+		CurrencyId::DexShare(DexShare::ForeignAsset(258), DexShare::Token(TokenSymbol::SEE))
+			.split_dex_share_currency_id(),
+		Some((foreign_asset, CurrencyId::Token(TokenSymbol::SEE)))
+	);
+}
+
+#[test]
+fn stable_asset_pool_token_works() {
+	let pool_token = CurrencyId::StableAssetPoolToken(1);
+	assert!(pool_token.is_stable_asset_pool_token_currency_id());
+	assert!(!pool_token.is_trading_pair_currency_id());
+	assert_eq!(pool_token.name(), Some("Stable Asset Pool Token"));
+	assert_eq!(pool_token.symbol(), None);
+	assert_eq!(pool_token.decimals(), Some(18));
+
+	assert_eq!(
+		TradingPair::from_currency_ids(pool_token, CurrencyId::Token(TokenSymbol::SEE)),
+		None
+	);
+	assert_eq!(
+		CurrencyId::join_dex_share_currency_id(pool_token, CurrencyId::Token(TokenSymbol::SEE)),
+		None
+	);
 }
 
 #[test]
@@ -142,3 +195,74 @@ fn generate_function_selector_works() {
 	assert_eq!(Action::BalanceOf as u32, 0x70a08231_u32);
 	assert_eq!(Action::Transfer as u32, 0xa9059cbb_u32);
 }
+
+#[test]
+fn currency_id_max_encoded_len_works() {
+	assert_eq!(TokenSymbol::max_encoded_len(), 1);
+	// Erc20 is the largest DexShare: 1 byte index + 20 bytes address
+	assert_eq!(DexShare::max_encoded_len(), 21);
+	// DexShare(Erc20, Erc20) is the largest CurrencyId
+	assert_eq!(CurrencyId::max_encoded_len(), 43);
+	assert_eq!(TradingPair::max_encoded_len(), 86);
+
+	let lp_erc20 = CurrencyId::DexShare(
+		DexShare::Erc20(EvmAddress::repeat_byte(1)),
+		DexShare::Erc20(EvmAddress::repeat_byte(2)),
+	);
+	assert_eq!(lp_erc20.encode().len(), CurrencyId::max_encoded_len());
+	assert_eq!(CurrencyId::Token(TokenSymbol::SEE).encode().len(), 2);
+	assert_eq!(CurrencyId::StableAssetPoolToken(0).encode().len(), 5);
+	assert_eq!(CurrencyId::ForeignAsset(0).encode().len(), 3);
+}
+
+#[test]
+fn currency_id_ticker_works() {
+	assert_eq!(TokenSymbol::try_from("SETUSD"), Ok(TokenSymbol::SETUSD));
+	assert_eq!(TokenSymbol::try_from("setusd"), Err(()));
+	assert_eq!(TokenSymbol::SETR.to_string(), "SETR");
+
+	assert_eq!(CurrencyId::try_from("SETUSD"), Ok(CurrencyId::Token(TokenSymbol::SETUSD)));
+	assert_eq!(CurrencyId::try_from("NOTATOKEN"), Err(()));
+	assert_eq!(CurrencyId::Token(TokenSymbol::SEE).to_string(), "SEE");
+
+	let lp = CurrencyId::DexShare(DexShare::Token(TokenSymbol::SETUSD), DexShare::Token(TokenSymbol::SETR));
+	assert_eq!(lp.to_string(), "LP_SETUSD_SETR");
+	assert_eq!(CurrencyId::try_from("LP_SETUSD_SETR"), Ok(lp));
+	assert_eq!(CurrencyId::try_from("LP_SETUSD"), Err(()));
+	assert_eq!(CurrencyId::try_from("LP_SETUSD_NOTATOKEN"), Err(()));
+
+	let erc20 = EvmAddress::from_str("0x1111111111111111111111111111111111111111").unwrap();
+	assert_eq!(
+		CurrencyId::Erc20(erc20).to_string(),
+		"0x1111111111111111111111111111111111111111"
+	);
+	assert_eq!(
+		CurrencyId::try_from("0x1111111111111111111111111111111111111111"),
+		Ok(CurrencyId::Erc20(erc20))
+	);
+	assert_eq!(CurrencyId::try_from("0x11"), Err(()));
+
+	let lp_foreign_erc20 = CurrencyId::DexShare(DexShare::ForeignAsset(3), DexShare::Erc20(erc20));
+	assert_eq!(
+		lp_foreign_erc20.to_string(),
+		"LP_FA_3_0x1111111111111111111111111111111111111111"
+	);
+	assert_eq!(
+		CurrencyId::try_from(lp_foreign_erc20.to_string().as_str()),
+		Ok(lp_foreign_erc20)
+	);
+
+	assert_eq!(CurrencyId::StableAssetPoolToken(7).to_string(), "SA_7");
+	assert_eq!(CurrencyId::try_from("SA_7"), Ok(CurrencyId::StableAssetPoolToken(7)));
+	assert_eq!(CurrencyId::ForeignAsset(12).to_string(), "FA_12");
+	assert_eq!(CurrencyId::try_from("FA_12"), Ok(CurrencyId::ForeignAsset(12)));
+
+	// signs are not hex or decimal digits
+	assert_eq!(
+		CurrencyId::try_from("0x+111111111111111111111111111111111111111"),
+		Err(())
+	);
+	assert_eq!(CurrencyId::try_from("FA_+12"), Err(()));
+	assert_eq!(CurrencyId::try_from("SA_+7"), Err(()));
+	assert_eq!(CurrencyId::try_from("FA_"), Err(()));
+}
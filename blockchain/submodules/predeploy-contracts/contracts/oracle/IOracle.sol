@@ -6,4 +6,10 @@ interface IOracle {
     // Get the price of the currency_id.
     // Returns the price.
     function getPrice(address token) external view returns (uint256);
+
+    // Get the price of 1 whole token in 18 decimals, the same as getPrice,
+    // and the timestamp of the oracle feed.
+    // The timestamp is 0 if the price is not the oracle feed itself.
+    // Returns (price, timestamp).
+    function getPriceWithTimestamp(address token) external view returns (uint256, uint256);
 }
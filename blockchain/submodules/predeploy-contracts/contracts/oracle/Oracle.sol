@@ -28,4 +28,28 @@ contract Oracle is IOracle {
 
         return abi.decode(returnData, (uint256));
     }
+
+    /**
+     * @dev Get the price of 1 whole token of the currency_id in 18 decimals,
+     * the same as getPrice, and the timestamp of the oracle feed.
+     * The timestamp is 0 if the price is not the oracle feed itself.
+     * Returns the (price, timestamp)
+     */
+    function getPriceWithTimestamp(address token)
+    public
+    view
+    override
+    returns (uint256, uint256)
+    {
+        require(token != address(0), "Oracle: token is zero address");
+
+        (bool success, bytes memory returnData) = precompile.staticcall(abi.encodeWithSignature("getPriceWithTimestamp(address)", token));
+        assembly {
+            if eq(success, 0) {
+                revert(add(returnData, 0x20), returndatasize())
+            }
+        }
+
+        return abi.decode(returnData, (uint256, uint256));
+    }
 }